@@ -0,0 +1,206 @@
+//! Lossless token amounts.
+
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// The maximum number of decimals of a [`RawAmount`], so that `10^decimals` fits in a `u128`.
+pub const MAX_DECIMALS: u8 = 38;
+
+/// A token amount in the token's smallest unit, along with the token's decimals.
+///
+/// Formats exactly and supports checked arithmetic without going through `f64`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub struct RawAmount {
+    pub raw: u128,
+    pub decimals: u8,
+}
+
+/// An error that occurred while parsing or creating a [`RawAmount`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum ParseAmountError {
+    #[error("empty amount")]
+    Empty,
+
+    #[error("invalid digit in amount")]
+    InvalidDigit,
+
+    #[error("amount has more decimals than {0}")]
+    TooManyDecimals(u8),
+
+    #[error("amount overflows a u128")]
+    Overflow,
+}
+
+impl RawAmount {
+    /// Creates a new amount.
+    ///
+    /// Returns an error if `decimals` is greater than [`MAX_DECIMALS`].
+    pub fn new(raw: u128, decimals: u8) -> Result<Self, ParseAmountError> {
+        if decimals > MAX_DECIMALS {
+            return Err(ParseAmountError::TooManyDecimals(MAX_DECIMALS));
+        }
+        Ok(Self { raw, decimals })
+    }
+
+    /// Parses a raw integer amount, e.g. `"1500000"` with `6` decimals.
+    pub fn parse_raw(raw: &str, decimals: u8) -> Result<Self, ParseAmountError> {
+        Self::new(parse_digits(raw)?, decimals)
+    }
+
+    /// Parses a UI amount, e.g. `"1.5"` with `6` decimals.
+    ///
+    /// Returns an error if the amount has more significant decimals than `decimals`.
+    pub fn from_ui_str(s: &str, decimals: u8) -> Result<Self, ParseAmountError> {
+        let Self { decimals, .. } = Self::new(0, decimals)?;
+        let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+        if int.is_empty() && frac.is_empty() {
+            return Err(ParseAmountError::Empty);
+        }
+        let significant = frac.trim_end_matches('0');
+        if significant.len() > decimals as usize {
+            return Err(ParseAmountError::TooManyDecimals(decimals));
+        }
+        let int = if int.is_empty() { 0 } else { parse_digits(int)? };
+        let frac = if significant.is_empty() { 0 } else { parse_digits(significant)? };
+        let frac_scale = pow10(decimals - significant.len() as u8);
+        let raw = int
+            .checked_mul(pow10(decimals))
+            .and_then(|int| int.checked_add(frac * frac_scale))
+            .ok_or(ParseAmountError::Overflow)?;
+        Ok(Self { raw, decimals })
+    }
+
+    /// Returns the amount as an `f64`. This may lose precision.
+    pub fn to_f64(self) -> f64 {
+        self.raw as f64 / 10f64.powi(self.decimals as i32)
+    }
+
+    /// Returns the same amount with `decimals` decimals, or `None` if it would overflow or lose
+    /// precision.
+    pub fn rescale(self, decimals: u8) -> Option<Self> {
+        if decimals > MAX_DECIMALS {
+            return None;
+        }
+        let raw = if decimals >= self.decimals {
+            self.raw.checked_mul(pow10(decimals - self.decimals))?
+        } else {
+            let scale = pow10(self.decimals - decimals);
+            if self.raw % scale != 0 {
+                return None;
+            }
+            self.raw / scale
+        };
+        Some(Self { raw, decimals })
+    }
+
+    /// Checked addition. Returns `None` on overflow or if the decimals differ.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.with_same_decimals(rhs, u128::checked_add)
+    }
+
+    /// Checked subtraction. Returns `None` on underflow or if the decimals differ.
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.with_same_decimals(rhs, u128::checked_sub)
+    }
+
+    /// Checked multiplication by an integer. Returns `None` on overflow.
+    pub fn checked_mul(self, rhs: u128) -> Option<Self> {
+        Some(Self { raw: self.raw.checked_mul(rhs)?, decimals: self.decimals })
+    }
+
+    /// Checked division by an integer, rounding down. Returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: u128) -> Option<Self> {
+        Some(Self { raw: self.raw.checked_div(rhs)?, decimals: self.decimals })
+    }
+
+    fn with_same_decimals(self, rhs: Self, f: fn(u128, u128) -> Option<u128>) -> Option<Self> {
+        if self.decimals != rhs.decimals {
+            return None;
+        }
+        Some(Self { raw: f(self.raw, rhs.raw)?, decimals: self.decimals })
+    }
+}
+
+/// Formats the UI amount exactly, without trailing zeros, e.g. `1.5`.
+impl fmt::Display for RawAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let scale = pow10(self.decimals);
+        let (int, frac) = (self.raw / scale, self.raw % scale);
+        if frac == 0 {
+            return f.pad(&int.to_string());
+        }
+        let frac = format!("{frac:0width$}", width = self.decimals as usize);
+        f.pad(&format!("{int}.{}", frac.trim_end_matches('0')))
+    }
+}
+
+/// Parses a UI amount, using the number of fractional digits as the decimals, e.g. `1.50` is
+/// `150` with `2` decimals.
+impl FromStr for RawAmount {
+    type Err = ParseAmountError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let decimals = s.split_once('.').map_or(0, |(_, frac)| frac.len());
+        Self::from_ui_str(s, to_decimals(decimals as u64)?)
+    }
+}
+
+/// Converts the decimals of a token, as returned by the API, into the decimals of a [`RawAmount`].
+pub(crate) fn to_decimals(decimals: u64) -> Result<u8, ParseAmountError> {
+    u8::try_from(decimals).map_err(|_| ParseAmountError::TooManyDecimals(MAX_DECIMALS))
+}
+
+fn parse_digits(s: &str) -> Result<u128, ParseAmountError> {
+    if s.is_empty() {
+        return Err(ParseAmountError::Empty);
+    }
+    s.bytes().try_fold(0u128, |acc, b| {
+        if !b.is_ascii_digit() {
+            return Err(ParseAmountError::InvalidDigit);
+        }
+        acc.checked_mul(10)
+            .and_then(|acc| acc.checked_add((b - b'0') as u128))
+            .ok_or(ParseAmountError::Overflow)
+    })
+}
+
+fn pow10(exp: u8) -> u128 {
+    10u128.pow(exp as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_amount() {
+        let supply = RawAmount::parse_raw("999999999999999999123456789", 9).unwrap();
+        assert_eq!(supply.to_string(), "999999999999999999.123456789");
+        assert_eq!(RawAmount::from_ui_str(&supply.to_string(), 9).unwrap(), supply);
+
+        let amount = RawAmount::from_ui_str("1.5", 6).unwrap();
+        assert_eq!(amount, RawAmount { raw: 1_500_000, decimals: 6 });
+        assert_eq!(amount.to_string(), "1.5");
+        assert_eq!(RawAmount::from_ui_str("1.500000000", 6).unwrap(), amount);
+        assert_eq!("1.50".parse::<RawAmount>().unwrap(), RawAmount { raw: 150, decimals: 2 });
+        assert_eq!(RawAmount::new(42, 0).unwrap().to_string(), "42");
+        assert_eq!(RawAmount::new(5, 3).unwrap().to_string(), "0.005");
+
+        assert_eq!(
+            RawAmount::from_ui_str("1.0000001", 6),
+            Err(ParseAmountError::TooManyDecimals(6))
+        );
+        assert_eq!(RawAmount::from_ui_str("1,5", 6), Err(ParseAmountError::InvalidDigit));
+        assert_eq!(RawAmount::from_ui_str(".", 6), Err(ParseAmountError::Empty));
+        assert_eq!(RawAmount::new(1, 39), Err(ParseAmountError::TooManyDecimals(38)));
+        assert_eq!(to_decimals(256), Err(ParseAmountError::TooManyDecimals(38)));
+
+        let a = RawAmount { raw: 1, decimals: 9 };
+        assert_eq!(a.checked_add(a), Some(RawAmount { raw: 2, decimals: 9 }));
+        assert_eq!(a.checked_sub(a.checked_mul(2).unwrap()), None);
+        assert_eq!(a.checked_add(RawAmount { raw: 1, decimals: 6 }), None);
+        assert_eq!(amount.rescale(2), Some(RawAmount { raw: 150, decimals: 2 }));
+        assert_eq!(amount.rescale(0), None);
+    }
+}
@@ -0,0 +1,85 @@
+//! Client-side Pro API credit accounting.
+
+use crate::{ClientError, Result};
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+};
+
+/// The estimated number of credits charged for a Pro API request, unless overridden with
+/// [`Client::with_route_credits`](crate::Client::with_route_credits).
+pub const DEFAULT_REQUEST_CREDITS: u64 = 100;
+
+/// Pro API routes that are not charged or limited by the credit budget, so that the real usage
+/// can still be queried once the budget is used up.
+pub(crate) const FREE_ROUTES: &[&str] = &["monitor/usage"];
+
+/// The number of requests sent and the estimated credits spent on a single route.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RouteUsage {
+    pub requests: u64,
+    pub credits: u64,
+}
+
+/// The number of requests sent and the estimated credits spent by a client.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CreditUsage {
+    pub requests: u64,
+    pub credits: u64,
+    /// The usage of every route, keyed by path.
+    pub routes: HashMap<String, RouteUsage>,
+}
+
+/// Tracks the credit usage shared by a client and its clones.
+#[derive(Debug, Default)]
+pub(crate) struct CreditMeter(Mutex<CreditUsage>);
+
+impl CreditMeter {
+    /// Records a request to `route` costing `credits`, unless it would exceed `budget`.
+    pub(crate) fn charge(&self, route: &str, credits: u64, budget: Option<u64>) -> Result<()> {
+        let mut usage = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        let used = usage.credits.saturating_add(credits);
+        if let Some(budget) = budget {
+            if used > budget {
+                return Err(ClientError::BudgetExceeded { budget, used: usage.credits });
+            }
+        }
+        usage.requests += 1;
+        usage.credits = used;
+        let route = usage.routes.entry(route.to_string()).or_default();
+        route.requests += 1;
+        route.credits += credits;
+        Ok(())
+    }
+
+    pub(crate) fn usage(&self) -> CreditUsage {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    pub(crate) fn reset(&self) {
+        *self.0.lock().unwrap_or_else(PoisonError::into_inner) = CreditUsage::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_charge() {
+        let meter = CreditMeter::default();
+        meter.charge("account/detail", 100, Some(250)).unwrap();
+        meter.charge("account/portfolio", 100, Some(250)).unwrap();
+        let err = meter.charge("account/detail", 100, Some(250)).unwrap_err();
+        assert!(matches!(err, ClientError::BudgetExceeded { budget: 250, used: 200 }));
+        meter.charge("account/detail", 50, Some(250)).unwrap();
+
+        let usage = meter.usage();
+        assert_eq!(usage.requests, 3);
+        assert_eq!(usage.credits, 250);
+        assert_eq!(usage.routes["account/detail"], RouteUsage { requests: 2, credits: 150 });
+
+        meter.reset();
+        assert_eq!(meter.usage(), CreditUsage::default());
+    }
+}
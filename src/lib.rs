@@ -5,36 +5,84 @@
 #[macro_use]
 mod macros;
 
-#[cfg(feature = "sdk")]
-#[path = "serde_string_sdk.rs"]
 mod serde_string;
 
-#[cfg(not(feature = "sdk"))]
-mod serde_string;
+#[cfg(feature = "chrono")]
+#[path = "serde_time_chrono.rs"]
+mod serde_time;
+
+#[cfg(not(feature = "chrono"))]
+mod serde_time;
+
+pub mod solana;
 
-mod solana;
+mod time;
+pub use time::{Date, Timestamp, UnixTime};
 
 mod response;
-use response::Response;
 pub use response::{ClientError, ResponseError, ResponseErrorMessage, Result};
+use response::{ProResponse, Response};
 
 mod routes;
 pub use routes::*;
 
+mod amount;
+pub use amount::{ParseAmountError, RawAmount, MAX_DECIMALS};
+
+mod parsed_instructions;
+pub use parsed_instructions::*;
+
+#[cfg(feature = "transaction-status")]
+mod transaction_status;
+
+mod pagination;
+pub use pagination::DEFAULT_PAGE_SIZE;
+
+mod credits;
+use credits::{CreditMeter, FREE_ROUTES};
+pub use credits::{CreditUsage, RouteUsage, DEFAULT_REQUEST_CREDITS};
+
 use reqwest::{header, Client as RClient, RequestBuilder};
 use serde::{de::DeserializeOwned, Serialize};
+use std::{collections::HashMap, fmt, sync::Arc};
 
+pub use futures_util::Stream;
 pub use reqwest::{self, Client as ReqwestClient, ClientBuilder as ReqwestClientBuilder, IntoUrl};
 pub use url::Url;
 
 /// The [Solscan API URL](https://public-api.solscan.io/docs).
 pub const BASE_URL: &str = "https://public-api.solscan.io/";
 
+/// The [Solscan Pro API URL](https://pro-api.solscan.io/pro-api-docs/v2.0).
+pub const PRO_BASE_URL: &str = "https://pro-api.solscan.io/v2.0/";
+
 /// A [Solscan API](https://public-api.solscan.io/docs) client.
-#[derive(Clone, Debug)]
+///
+/// Clones share the same Pro API [credit usage](Client::credit_usage).
+#[derive(Clone)]
 pub struct Client {
     client: RClient,
     base_url: Url,
+    pro_url: Url,
+    api_key: Option<String>,
+    credits: Arc<CreditMeter>,
+    credit_budget: Option<u64>,
+    route_credits: HashMap<String, u64>,
+}
+
+/// Redacts the API key.
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("client", &self.client)
+            .field("base_url", &self.base_url)
+            .field("pro_url", &self.pro_url)
+            .field("api_key", &self.api_key.as_ref().map(|_| "<redacted>"))
+            .field("credits", &self.credits)
+            .field("credit_budget", &self.credit_budget)
+            .field("route_credits", &self.route_credits)
+            .finish()
+    }
 }
 
 impl Default for Client {
@@ -56,7 +104,60 @@ impl Client {
 
     /// Instantiate a new client with the provided URL and reqwest client.
     pub fn with_url_and_client(url: impl IntoUrl, client: RClient) -> Result<Self> {
-        Ok(Self { client, base_url: url.into_url()? })
+        Ok(Self {
+            client,
+            base_url: url.into_url()?,
+            pro_url: Url::parse(PRO_BASE_URL)?,
+            api_key: None,
+            credits: Arc::default(),
+            credit_budget: None,
+            route_credits: HashMap::new(),
+        })
+    }
+
+    /// Sets the Pro API URL. Defaults to [`PRO_BASE_URL`].
+    pub fn with_pro_url(mut self, url: impl IntoUrl) -> Result<Self> {
+        self.pro_url = url.into_url()?;
+        Ok(self)
+    }
+
+    /// Sets the API key that is sent with every Pro API request.
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Sets the maximum number of credits that Pro API requests may spend.
+    ///
+    /// Once the budget is used up, Pro API requests return [`ClientError::BudgetExceeded`]
+    /// without being sent.
+    pub fn with_credit_budget(mut self, credits: u64) -> Self {
+        self.credit_budget = Some(credits);
+        self
+    }
+
+    /// Sets the estimated number of credits charged for a request to the Pro API `path`.
+    /// Defaults to [`DEFAULT_REQUEST_CREDITS`].
+    ///
+    /// [`Client::monitor_usage`] is never charged.
+    pub fn with_route_credits(mut self, path: impl Into<String>, credits: u64) -> Self {
+        self.route_credits.insert(path.into(), credits);
+        self
+    }
+
+    /// Returns the number of Pro API requests sent and the estimated credits spent.
+    pub fn credit_usage(&self) -> CreditUsage {
+        self.credits.usage()
+    }
+
+    /// Returns the credits left in the [budget](Client::with_credit_budget), if any.
+    pub fn remaining_credits(&self) -> Option<u64> {
+        self.credit_budget.map(|budget| budget.saturating_sub(self.credits.usage().credits))
+    }
+
+    /// Resets the [credit usage](Client::credit_usage).
+    pub fn reset_credit_usage(&self) {
+        self.credits.reset()
     }
 
     /// Performs an HTTP `GET` request.
@@ -73,10 +174,50 @@ impl Client {
         self._get(path)?.send().await?.json::<Response<T>>().await?.result()
     }
 
+    /// Performs an HTTP `GET` request to the Pro API.
+    pub async fn get_pro<T: DeserializeOwned, Q: Serialize + ?Sized>(
+        &self,
+        path: &str,
+        query: &Q,
+    ) -> Result<T> {
+        self._get_pro(path)?
+            .query(query)
+            .send()
+            .await?
+            .json::<Response<ProResponse<T>>>()
+            .await?
+            .result()?
+            .result()
+    }
+
+    /// Performs an HTTP `GET` request to the Pro API without a query string.
+    pub async fn get_pro_no_query<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        self._get_pro(path)?
+            .send()
+            .await?
+            .json::<Response<ProResponse<T>>>()
+            .await?
+            .result()?
+            .result()
+    }
+
     fn _get(&self, path: &str) -> Result<RequestBuilder> {
         let url = self.base_url.join(path)?;
         Ok(self.client.get(url).header(header::ACCEPT, "application/json"))
     }
+
+    fn _get_pro(&self, path: &str) -> Result<RequestBuilder> {
+        let url = self.pro_url.join(path)?;
+        if !FREE_ROUTES.contains(&path) {
+            let credits = self.route_credits.get(path).copied().unwrap_or(DEFAULT_REQUEST_CREDITS);
+            self.credits.charge(path, credits, self.credit_budget)?;
+        }
+        let builder = self.client.get(url).header(header::ACCEPT, "application/json");
+        Ok(match &self.api_key {
+            Some(api_key) => builder.header("token", api_key),
+            None => builder,
+        })
+    }
 }
 
 #[inline]
@@ -86,3 +227,27 @@ fn concat_1(base_path: &str, value: &str) -> String {
     s.push_str(value);
     s
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_redacts_api_key() {
+        let client = Client::new().with_api_key("secret-key");
+        let debug = format!("{client:?}");
+        assert!(!debug.contains("secret-key"));
+        assert!(debug.contains("<redacted>"));
+    }
+
+    #[test]
+    fn test_monitor_usage_is_free() {
+        let client = Client::new().with_credit_budget(0);
+        assert!(client._get_pro("monitor/usage").is_ok());
+        assert_eq!(client.credit_usage().credits, 0);
+        assert!(matches!(
+            client._get_pro("account/detail"),
+            Err(ClientError::BudgetExceeded { budget: 0, used: 0 })
+        ));
+    }
+}
@@ -7,6 +7,15 @@ macro_rules! api_models {
     )+};
 }
 
+/// Common derives and other attributes for Solscan Pro API models, which use `snake_case` keys.
+macro_rules! pro_api_models {
+    ($($item:item)+) => {$(
+        #[derive(Clone, Debug, Default, PartialEq, ::serde::Deserialize, ::serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        $item
+    )+};
+}
+
 /// Easier debugging by retrying deserialization by turning an unknown value into a string.
 #[cfg(test)]
 macro_rules! test_route {
@@ -17,7 +26,10 @@ macro_rules! test_route {
         #[::tokio::test]
         $(#[$attr])*
         async fn $name() {
-            let $client = crate::Client::new();
+            let mut $client = crate::Client::new();
+            if let Ok(api_key) = ::std::env::var("SOLSCAN_API_KEY") {
+                $client = $client.with_api_key(api_key);
+            }
             let result: ::core::result::Result<$ty, $crate::ClientError> = $route_call.await;
             match result {
                 Ok($x) => $block,
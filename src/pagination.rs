@@ -0,0 +1,72 @@
+//! Pagination over Pro API list routes.
+
+use crate::{ClientError, Result};
+use futures_util::{stream, Stream, StreamExt};
+use std::future::Future;
+
+/// The default number of items requested per page by the Pro API pagination streams.
+pub const DEFAULT_PAGE_SIZE: u64 = 100;
+
+/// Returns a stream of all the items returned by `fetch`, starting from `page` and requesting the
+/// next page until a page with less than `page_size` items is returned.
+///
+/// The stream ends after the first error.
+pub(crate) fn paginate<'a, T, F, Fut>(
+    page: u64,
+    page_size: u64,
+    mut fetch: F,
+) -> impl Stream<Item = Result<T>> + 'a
+where
+    T: 'a,
+    F: FnMut(u64) -> Fut + 'a,
+    Fut: Future<Output = Result<Vec<T>>> + 'a,
+{
+    stream::unfold(Some(page), move |page| {
+        let next = page.map(|page| (page, fetch(page)));
+        async move {
+            let (page, fut) = next?;
+            match fut.await {
+                Ok(items) => {
+                    let next = if (items.len() as u64) < page_size { None } else { Some(page + 1) };
+                    Some((Ok(items), next))
+                }
+                Err(ClientError::EmptyResponse) => None,
+                Err(e) => Some((Err(e), None)),
+            }
+        }
+    })
+    .flat_map(|page| {
+        stream::iter(match page {
+            Ok(items) => items.into_iter().map(Ok).collect(),
+            Err(e) => vec![Err(e)],
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::TryStreamExt;
+
+    #[tokio::test]
+    async fn test_paginate() {
+        let pages = paginate(1, 2, |page| async move {
+            Ok(match page {
+                1 => vec![1, 2],
+                2 => vec![3, 4],
+                3 => vec![5],
+                _ => unreachable!(),
+            })
+        });
+        assert_eq!(pages.try_collect::<Vec<_>>().await.unwrap(), [1, 2, 3, 4, 5]);
+
+        let pages = paginate(3, 2, |page| async move {
+            if page == 3 {
+                Ok(vec![1, 2])
+            } else {
+                Err(ClientError::EmptyResponse)
+            }
+        });
+        assert_eq!(pages.try_collect::<Vec<_>>().await.unwrap(), [1, 2]);
+    }
+}
@@ -0,0 +1,669 @@
+//! Typed instructions of common programs, as parsed by the API.
+
+use crate::{solana::Pubkey, TokenAmount};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+/// Program IDs of the programs parsed into [`ParsedInstructionKind`].
+pub mod program_ids {
+    pub const SYSTEM: &str = "11111111111111111111111111111111";
+    pub const TOKEN: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+    pub const TOKEN_2022: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+    pub const ASSOCIATED_TOKEN: &str = "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL";
+    pub const MEMO: &str = "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr";
+    pub const MEMO_V1: &str = "Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo";
+    pub const COMPUTE_BUDGET: &str = "ComputeBudget111111111111111111111111111111";
+    pub const STAKE: &str = "Stake11111111111111111111111111111111111111";
+    pub const VOTE: &str = "Vote111111111111111111111111111111111111111";
+}
+
+/// A parsed instruction of a known program.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParsedInstructionKind {
+    System(SystemInstruction),
+    Token(TokenInstruction),
+    Token2022(TokenInstruction),
+    AssociatedToken(AssociatedTokenInstruction),
+    Memo(String),
+    ComputeBudget(ComputeBudgetInstruction),
+    Stake(StakeInstruction),
+    Vote(VoteInstruction),
+    /// An instruction of any other program, or one that could not be parsed.
+    Unknown(Value),
+}
+
+impl ParsedInstructionKind {
+    /// Parses the `{ "type": ..., "info": ... }` object returned by the API for an instruction of
+    /// `program_id`.
+    ///
+    /// Falls back to [`ParsedInstructionKind::Unknown`] with the raw value if the program or the
+    /// instruction type is not known.
+    pub fn parse(program_id: &Pubkey, parsed: &Value) -> Self {
+        fn from_value<T: DeserializeOwned>(
+            value: &Value,
+            f: fn(T) -> ParsedInstructionKind,
+        ) -> ParsedInstructionKind {
+            match T::deserialize(value) {
+                Ok(x) => f(x),
+                Err(_) => ParsedInstructionKind::Unknown(value.clone()),
+            }
+        }
+
+        use program_ids::*;
+        match program_id.to_string().as_str() {
+            SYSTEM => from_value(parsed, Self::System),
+            TOKEN => from_value(parsed, Self::Token),
+            TOKEN_2022 => from_value(parsed, Self::Token2022),
+            ASSOCIATED_TOKEN => from_value(parsed, Self::AssociatedToken),
+            MEMO | MEMO_V1 => from_value(parsed, Self::Memo),
+            COMPUTE_BUDGET => from_value(parsed, Self::ComputeBudget),
+            STAKE => from_value(parsed, Self::Stake),
+            VOTE => from_value(parsed, Self::Vote),
+            _ => Self::Unknown(parsed.clone()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", content = "info", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum SystemInstruction {
+    CreateAccount {
+        #[serde(with = "crate::serde_string")]
+        source: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        new_account: Pubkey,
+        lamports: u64,
+        space: u64,
+        #[serde(with = "crate::serde_string")]
+        owner: Pubkey,
+    },
+    CreateAccountWithSeed {
+        #[serde(with = "crate::serde_string")]
+        source: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        new_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        base: Pubkey,
+        seed: String,
+        lamports: u64,
+        space: u64,
+        #[serde(with = "crate::serde_string")]
+        owner: Pubkey,
+    },
+    Assign {
+        #[serde(with = "crate::serde_string")]
+        account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        owner: Pubkey,
+    },
+    Transfer {
+        #[serde(with = "crate::serde_string")]
+        source: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        destination: Pubkey,
+        lamports: u64,
+    },
+    TransferWithSeed {
+        #[serde(with = "crate::serde_string")]
+        source: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        source_base: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        destination: Pubkey,
+        lamports: u64,
+        source_seed: String,
+        #[serde(with = "crate::serde_string")]
+        source_owner: Pubkey,
+    },
+    Allocate {
+        #[serde(with = "crate::serde_string")]
+        account: Pubkey,
+        space: u64,
+    },
+    AdvanceNonce {
+        #[serde(with = "crate::serde_string")]
+        nonce_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        nonce_authority: Pubkey,
+    },
+    WithdrawFromNonce {
+        #[serde(with = "crate::serde_string")]
+        nonce_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        destination: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        nonce_authority: Pubkey,
+        lamports: u64,
+    },
+    InitializeNonce {
+        #[serde(with = "crate::serde_string")]
+        nonce_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        nonce_authority: Pubkey,
+    },
+    AuthorizeNonce {
+        #[serde(with = "crate::serde_string")]
+        nonce_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        nonce_authority: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        new_authorized: Pubkey,
+    },
+}
+
+/// An SPL Token or Token-2022 instruction.
+///
+/// The `authority` fields are `None` when the instruction is signed by a multisig, in which case
+/// `multisig_authority` is set instead.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", content = "info", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum TokenInstruction {
+    #[serde(alias = "initializeMint2")]
+    InitializeMint {
+        #[serde(with = "crate::serde_string")]
+        mint: Pubkey,
+        decimals: u8,
+        #[serde(with = "crate::serde_string")]
+        mint_authority: Pubkey,
+        #[serde(default, with = "crate::serde_string::option")]
+        freeze_authority: Option<Pubkey>,
+    },
+    #[serde(alias = "initializeAccount2", alias = "initializeAccount3")]
+    InitializeAccount {
+        #[serde(with = "crate::serde_string")]
+        account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        mint: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        owner: Pubkey,
+    },
+    Transfer {
+        #[serde(with = "crate::serde_string")]
+        source: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        destination: Pubkey,
+        #[serde(default, with = "crate::serde_string::option")]
+        authority: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        multisig_authority: Option<Pubkey>,
+        amount: String,
+    },
+    TransferChecked {
+        #[serde(with = "crate::serde_string")]
+        source: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        mint: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        destination: Pubkey,
+        #[serde(default, with = "crate::serde_string::option")]
+        authority: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        multisig_authority: Option<Pubkey>,
+        token_amount: TokenAmount,
+    },
+    Approve {
+        #[serde(with = "crate::serde_string")]
+        source: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        delegate: Pubkey,
+        #[serde(default, with = "crate::serde_string::option")]
+        owner: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        multisig_owner: Option<Pubkey>,
+        amount: String,
+    },
+    Revoke {
+        #[serde(with = "crate::serde_string")]
+        source: Pubkey,
+        #[serde(default, with = "crate::serde_string::option")]
+        owner: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        multisig_owner: Option<Pubkey>,
+    },
+    SetAuthority {
+        authority_type: String,
+        #[serde(default, with = "crate::serde_string::option")]
+        new_authority: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        authority: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        multisig_authority: Option<Pubkey>,
+    },
+    MintTo {
+        #[serde(with = "crate::serde_string")]
+        mint: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        account: Pubkey,
+        #[serde(default, with = "crate::serde_string::option")]
+        mint_authority: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        multisig_mint_authority: Option<Pubkey>,
+        amount: String,
+    },
+    MintToChecked {
+        #[serde(with = "crate::serde_string")]
+        mint: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        account: Pubkey,
+        #[serde(default, with = "crate::serde_string::option")]
+        mint_authority: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        multisig_mint_authority: Option<Pubkey>,
+        token_amount: TokenAmount,
+    },
+    Burn {
+        #[serde(with = "crate::serde_string")]
+        account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        mint: Pubkey,
+        #[serde(default, with = "crate::serde_string::option")]
+        authority: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        multisig_authority: Option<Pubkey>,
+        amount: String,
+    },
+    BurnChecked {
+        #[serde(with = "crate::serde_string")]
+        account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        mint: Pubkey,
+        #[serde(default, with = "crate::serde_string::option")]
+        authority: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        multisig_authority: Option<Pubkey>,
+        token_amount: TokenAmount,
+    },
+    CloseAccount {
+        #[serde(with = "crate::serde_string")]
+        account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        destination: Pubkey,
+        #[serde(default, with = "crate::serde_string::option")]
+        owner: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        multisig_owner: Option<Pubkey>,
+    },
+    FreezeAccount {
+        #[serde(with = "crate::serde_string")]
+        account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        mint: Pubkey,
+        #[serde(default, with = "crate::serde_string::option")]
+        freeze_authority: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        multisig_freeze_authority: Option<Pubkey>,
+    },
+    ThawAccount {
+        #[serde(with = "crate::serde_string")]
+        account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        mint: Pubkey,
+        #[serde(default, with = "crate::serde_string::option")]
+        freeze_authority: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        multisig_freeze_authority: Option<Pubkey>,
+    },
+    SyncNative {
+        #[serde(with = "crate::serde_string")]
+        account: Pubkey,
+    },
+    InitializeImmutableOwner {
+        #[serde(with = "crate::serde_string")]
+        account: Pubkey,
+    },
+    GetAccountDataSize {
+        #[serde(with = "crate::serde_string")]
+        mint: Pubkey,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", content = "info", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum AssociatedTokenInstruction {
+    #[serde(alias = "createIdempotent")]
+    Create {
+        #[serde(with = "crate::serde_string")]
+        source: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        wallet: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        mint: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        token_program: Pubkey,
+    },
+    RecoverNested {
+        #[serde(with = "crate::serde_string")]
+        nested_source: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        nested_mint: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        destination: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        wallet: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        token_program: Pubkey,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", content = "info", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum ComputeBudgetInstruction {
+    RequestHeapFrame { bytes: u32 },
+    SetComputeUnitLimit { units: u32 },
+    SetComputeUnitPrice { micro_lamports: u64 },
+    SetLoadedAccountsDataSizeLimit { bytes: u32 },
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", content = "info", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum StakeInstruction {
+    Initialize {
+        #[serde(with = "crate::serde_string")]
+        stake_account: Pubkey,
+        authorized: StakeAuthorized,
+        #[serde(default)]
+        lockup: Value,
+    },
+    Authorize {
+        #[serde(with = "crate::serde_string")]
+        stake_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        authority: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        new_authority: Pubkey,
+        authority_type: String,
+    },
+    #[serde(rename = "delegate")]
+    DelegateStake {
+        #[serde(with = "crate::serde_string")]
+        stake_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        vote_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        stake_authority: Pubkey,
+    },
+    Split {
+        #[serde(with = "crate::serde_string")]
+        stake_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        new_split_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        stake_authority: Pubkey,
+        lamports: u64,
+    },
+    Withdraw {
+        #[serde(with = "crate::serde_string")]
+        stake_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        destination: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        withdraw_authority: Pubkey,
+        lamports: u64,
+    },
+    Deactivate {
+        #[serde(with = "crate::serde_string")]
+        stake_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        stake_authority: Pubkey,
+    },
+    Merge {
+        #[serde(with = "crate::serde_string")]
+        destination: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        source: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        stake_authority: Pubkey,
+    },
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StakeAuthorized {
+    #[serde(with = "crate::serde_string")]
+    pub staker: Pubkey,
+    #[serde(with = "crate::serde_string")]
+    pub withdrawer: Pubkey,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", content = "info", rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum VoteInstruction {
+    Vote {
+        #[serde(with = "crate::serde_string")]
+        vote_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        vote_authority: Pubkey,
+        vote: Value,
+    },
+    #[serde(
+        alias = "updatevotestate",
+        alias = "compactupdatevotestate",
+        alias = "towersync",
+        rename = "updateVoteState"
+    )]
+    UpdateVoteState {
+        #[serde(with = "crate::serde_string")]
+        vote_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        vote_authority: Pubkey,
+        #[serde(alias = "towerSync")]
+        vote_state_update: Value,
+    },
+    Withdraw {
+        #[serde(with = "crate::serde_string")]
+        vote_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        destination: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        withdraw_authority: Pubkey,
+        lamports: u64,
+    },
+    Authorize {
+        #[serde(with = "crate::serde_string")]
+        vote_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        authority: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        new_authority: Pubkey,
+        authority_type: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse() {
+        let system = program_ids::SYSTEM.parse().unwrap();
+        let transfer = json!({
+            "type": "transfer",
+            "info": {
+                "source": "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn",
+                "destination": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+                "lamports": 5000
+            }
+        });
+        let kind = ParsedInstructionKind::parse(&system, &transfer);
+        let ParsedInstructionKind::System(SystemInstruction::Transfer { lamports, .. }) = kind
+        else {
+            panic!("{kind:?}");
+        };
+        assert_eq!(lamports, 5000);
+
+        let limit = json!({ "type": "setComputeUnitLimit", "info": { "units": 200000 } });
+        let kind =
+            ParsedInstructionKind::parse(&program_ids::COMPUTE_BUDGET.parse().unwrap(), &limit);
+        assert_eq!(
+            kind,
+            ParsedInstructionKind::ComputeBudget(ComputeBudgetInstruction::SetComputeUnitLimit {
+                units: 200000
+            })
+        );
+
+        let memo = json!("hello");
+        let kind = ParsedInstructionKind::parse(&program_ids::MEMO.parse().unwrap(), &memo);
+        assert_eq!(kind, ParsedInstructionKind::Memo("hello".into()));
+
+        let unknown = json!({ "type": "somethingNew", "info": {} });
+        let kind = ParsedInstructionKind::parse(&system, &unknown);
+        assert_eq!(kind, ParsedInstructionKind::Unknown(unknown));
+    }
+
+    #[test]
+    fn test_parse_token() {
+        let transfer = json!({
+            "type": "transferChecked",
+            "info": {
+                "source": "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn",
+                "mint": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+                "destination": "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2",
+                "multisigAuthority": "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8",
+                "signers": ["3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn"],
+                "tokenAmount": {
+                    "amount": "1500000",
+                    "decimals": 6,
+                    "uiAmount": 1.5,
+                    "uiAmountString": "1.5"
+                }
+            }
+        });
+        let kind = ParsedInstructionKind::parse(&program_ids::TOKEN.parse().unwrap(), &transfer);
+        let ParsedInstructionKind::Token(TokenInstruction::TransferChecked {
+            authority,
+            multisig_authority,
+            token_amount,
+            ..
+        }) = kind
+        else {
+            panic!("{kind:?}");
+        };
+        assert_eq!(authority, None);
+        assert!(multisig_authority.is_some());
+        assert_eq!(token_amount.ui_amount_string, "1.5");
+
+        let init = json!({
+            "type": "initializeAccount3",
+            "info": {
+                "account": "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn",
+                "mint": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+                "owner": "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2"
+            }
+        });
+        let kind = ParsedInstructionKind::parse(&program_ids::TOKEN_2022.parse().unwrap(), &init);
+        assert!(
+            matches!(
+                kind,
+                ParsedInstructionKind::Token2022(TokenInstruction::InitializeAccount { .. })
+            ),
+            "{kind:?}"
+        );
+    }
+
+    #[test]
+    fn test_parse_associated_token() {
+        let create = json!({
+            "type": "createIdempotent",
+            "info": {
+                "source": "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn",
+                "account": "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2",
+                "wallet": "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn",
+                "mint": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+                "systemProgram": program_ids::SYSTEM,
+                "tokenProgram": program_ids::TOKEN
+            }
+        });
+        let kind =
+            ParsedInstructionKind::parse(&program_ids::ASSOCIATED_TOKEN.parse().unwrap(), &create);
+        let ParsedInstructionKind::AssociatedToken(AssociatedTokenInstruction::Create {
+            token_program,
+            ..
+        }) = kind
+        else {
+            panic!("{kind:?}");
+        };
+        assert_eq!(token_program.to_string(), program_ids::TOKEN);
+    }
+
+    #[test]
+    fn test_parse_stake() {
+        let stake = program_ids::STAKE.parse().unwrap();
+        let delegate = json!({
+            "type": "delegate",
+            "info": {
+                "stakeAccount": "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn",
+                "voteAccount": "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2",
+                "stakeAuthority": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+                "clockSysvar": "SysvarC1ock11111111111111111111111111111111"
+            }
+        });
+        let kind = ParsedInstructionKind::parse(&stake, &delegate);
+        assert!(
+            matches!(kind, ParsedInstructionKind::Stake(StakeInstruction::DelegateStake { .. })),
+            "{kind:?}"
+        );
+
+        let initialize = json!({
+            "type": "initialize",
+            "info": {
+                "stakeAccount": "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn",
+                "authorized": {
+                    "staker": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+                    "withdrawer": "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2"
+                },
+                "lockup": { "custodian": program_ids::SYSTEM, "epoch": 0, "unixTimestamp": 0 }
+            }
+        });
+        let kind = ParsedInstructionKind::parse(&stake, &initialize);
+        let ParsedInstructionKind::Stake(StakeInstruction::Initialize { authorized, .. }) = kind
+        else {
+            panic!("{kind:?}");
+        };
+        assert_eq!(
+            authorized.withdrawer.to_string(),
+            "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2"
+        );
+    }
+
+    #[test]
+    fn test_parse_vote() {
+        let vote = program_ids::VOTE.parse().unwrap();
+        let tower_sync = json!({
+            "type": "towersync",
+            "info": {
+                "voteAccount": "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn",
+                "voteAuthority": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+                "towerSync": { "root": 254000000 }
+            }
+        });
+        let kind = ParsedInstructionKind::parse(&vote, &tower_sync);
+        let ParsedInstructionKind::Vote(VoteInstruction::UpdateVoteState {
+            vote_state_update, ..
+        }) = kind
+        else {
+            panic!("{kind:?}");
+        };
+        assert_eq!(vote_state_update, json!({ "root": 254000000 }));
+
+        let withdraw = json!({
+            "type": "withdraw",
+            "info": {
+                "voteAccount": "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn",
+                "destination": "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2",
+                "withdrawAuthority": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+                "lamports": 1000
+            }
+        });
+        let kind = ParsedInstructionKind::parse(&vote, &withdraw);
+        assert!(
+            matches!(
+                kind,
+                ParsedInstructionKind::Vote(VoteInstruction::Withdraw { lamports: 1000, .. })
+            ),
+            "{kind:?}"
+        );
+    }
+}
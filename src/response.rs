@@ -18,6 +18,9 @@ pub enum ClientError {
     #[error("Received an unknown response: {0}")]
     UnknownResponse(Value),
 
+    #[error("Credit budget of {budget} exceeded: {used} credits already used")]
+    BudgetExceeded { budget: u64, used: u64 },
+
     #[error(transparent)]
     Reqwest(#[from] reqwest::Error),
 
@@ -45,6 +48,19 @@ api_models! {
     }
 }
 
+pro_api_models! {
+    pub(crate) struct ProResponseError {
+        pub code: i32,
+        pub message: String,
+    }
+
+    pub(crate) struct ProResponse<T> {
+        pub success: bool,
+        pub data: Option<T>,
+        pub errors: Option<ProResponseError>,
+    }
+}
+
 impl<T> Response<T> {
     pub fn result(self) -> Result<T> {
         match self {
@@ -60,3 +76,47 @@ impl<T> Response<T> {
         }
     }
 }
+
+impl<T> ProResponse<T> {
+    pub fn result(self) -> Result<T> {
+        match self {
+            Self { errors: Some(ProResponseError { code, message }), .. } => {
+                Err(ClientError::Response(ResponseError {
+                    status: code,
+                    error: ResponseErrorMessage { message },
+                }))
+            }
+            Self { data: Some(data), .. } => Ok(data),
+            _ => Err(ClientError::EmptyResponse),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pro_result(json: Value) -> Result<Vec<u64>> {
+        serde_json::from_value::<Response<ProResponse<Vec<u64>>>>(json)
+            .unwrap()
+            .result()
+            .and_then(ProResponse::result)
+    }
+
+    #[test]
+    fn test_pro_response() {
+        let ok = pro_result(serde_json::json!({ "success": true, "data": [1, 2, 3] }));
+        assert_eq!(ok.unwrap(), [1, 2, 3]);
+
+        let err = pro_result(serde_json::json!({
+            "success": false,
+            "errors": { "code": 1100, "message": "Validation Error" }
+        }));
+        let Err(ClientError::Response(err)) = err else { panic!("{err:?}") };
+        assert_eq!(err.status, 1100);
+        assert_eq!(err.error.message, "Validation Error");
+
+        let empty = pro_result(serde_json::json!({ "success": true, "data": null }));
+        assert!(matches!(empty, Err(ClientError::EmptyResponse)));
+    }
+}
@@ -1,10 +1,14 @@
 //! Account section.
 
 use crate::{
+    amount::to_decimals,
     concat_1,
+    pagination::paginate,
     solana::{Pubkey, Signature},
-    Client, Result,
+    Client, ParseAmountError, RawAmount, Result, Stream, Timestamp, TxStatus, UnixTime,
+    DEFAULT_PAGE_SIZE,
 };
+use std::fmt;
 
 // TODO: remaining routes: stakeAccounts, splTransfers, solTransfers, exportTransactions
 
@@ -25,23 +29,32 @@ api_models! {
     pub struct TokenAmount {
         pub amount: String,
         pub decimals: u64,
-        pub ui_amount: f64,
+        /// `None` when the amount is too large to be represented as an `f64`, or is zero in older
+        /// transactions.
+        pub ui_amount: Option<f64>,
         pub ui_amount_string: String,
     }
 
     pub struct AccountTransaction {
-        pub block_time: u64,
+        #[serde(with = "crate::serde_time")]
+        pub block_time: Timestamp,
         pub fee: u64,
         pub lamport: u64,
         pub parsed_instruction: Vec<ParsedInstruction>,
         #[serde(with = "crate::serde_string::vec")]
         pub signer: Vec<Pubkey>,
         pub slot: u64,
-        pub status: String,
+        pub status: TxStatus,
         #[serde(with = "crate::serde_string")]
         pub tx_hash: Signature,
     }
 
+    /// An instruction of an [`AccountTransaction`].
+    ///
+    /// Unlike [`TransactionParsedInstruction`](crate::TransactionParsedInstruction), the API only
+    /// returns the instruction type and not its arguments, so there is no typed
+    /// [`ParsedInstructionKind`](crate::ParsedInstructionKind) accessor. Fetch the transaction with
+    /// [`Client::transaction`] for that.
     pub struct ParsedInstruction {
         #[serde(with = "crate::serde_string")]
         pub program_id: Pubkey,
@@ -52,18 +65,747 @@ api_models! {
         pub lamports: u64,
         #[serde(with = "crate::serde_string")]
         pub owner_program: Pubkey,
-        pub r#type: String,
+        pub r#type: AccountType,
         pub rent_epoch: u64,
         #[serde(with = "crate::serde_string")]
         pub account: Pubkey,
     }
 }
 
-#[cfg(feature = "sdk")]
+pro_api_models! {
+    #[derive(Eq, Hash)]
+    pub enum AccountType {
+        /// A wallet owned by the System Program.
+        #[default]
+        SystemAccount,
+        /// An SPL Token or Token-2022 account.
+        TokenAccount,
+        /// An SPL Token or Token-2022 mint.
+        #[serde(rename = "token", alias = "mint")]
+        Mint,
+        /// An executable program.
+        Program,
+        /// A Stake Program account.
+        StakeAccount,
+        /// A Vote Program account.
+        VoteAccount,
+        /// Any other account type.
+        #[serde(untagged)]
+        Other(String),
+    }
+
+    pub struct AccountDetail {
+        #[serde(with = "crate::serde_string")]
+        pub account: Pubkey,
+        pub lamports: u64,
+        #[serde(with = "crate::serde_string")]
+        pub owner_program: Pubkey,
+        pub executable: bool,
+        pub rent_epoch: u64,
+        /// Whether the address lies on the ed25519 curve, i.e. it is not a program derived address.
+        #[serde(default)]
+        pub is_oncurve: bool,
+        /// The account type, along with any type-specific data.
+        #[serde(flatten)]
+        pub kind: AccountKind,
+    }
+
+    /// The type-specific data of an [`AccountDetail`], tagged by its [`AccountType`].
+    #[serde(tag = "type")]
+    pub enum AccountKind {
+        #[default]
+        SystemAccount,
+        TokenAccount(TokenAccountData),
+        #[serde(rename = "token", alias = "mint")]
+        Mint(MintData),
+        Program(ProgramData),
+        StakeAccount(StakeAccountData),
+        VoteAccount(VoteAccountData),
+        /// Any other account type, without type-specific data.
+        #[serde(untagged)]
+        Other {
+            #[serde(rename = "type")]
+            r#type: String,
+        },
+    }
+
+    pub struct TokenAccountData {
+        #[serde(default, with = "crate::serde_string::option")]
+        pub token_address: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        pub owner: Option<Pubkey>,
+        #[serde(default)]
+        pub amount: Option<String>,
+        #[serde(default)]
+        pub decimals: Option<u64>,
+    }
+
+    pub struct MintData {
+        #[serde(default)]
+        pub decimals: Option<u64>,
+        #[serde(default)]
+        pub supply: Option<String>,
+        #[serde(default, with = "crate::serde_string::option")]
+        pub mint_authority: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        pub freeze_authority: Option<Pubkey>,
+    }
+
+    pub struct ProgramData {
+        #[serde(default, with = "crate::serde_string::option")]
+        pub program_data: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        pub upgrade_authority: Option<Pubkey>,
+    }
+
+    pub struct StakeAccountData {
+        #[serde(default, with = "crate::serde_string::option")]
+        pub staker: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        pub withdrawer: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        pub voter: Option<Pubkey>,
+        #[serde(default)]
+        pub active_stake: Option<u64>,
+        #[serde(default)]
+        pub activation_epoch: Option<u64>,
+        #[serde(default)]
+        pub deactivation_epoch: Option<u64>,
+    }
+
+    pub struct VoteAccountData {
+        #[serde(default, with = "crate::serde_string::option")]
+        pub node_pubkey: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        pub authorized_withdrawer: Option<Pubkey>,
+        #[serde(default)]
+        pub commission: Option<u8>,
+    }
+}
+
+pro_api_models! {
+    pub struct DefiActivity {
+        pub block_id: u64,
+        #[serde(with = "crate::serde_string")]
+        pub trans_id: Signature,
+        #[serde(with = "crate::serde_time")]
+        pub block_time: Timestamp,
+        pub activity_type: DefiActivityType,
+        #[serde(with = "crate::serde_string")]
+        pub from_address: Pubkey,
+        #[serde(default, with = "crate::serde_string::option")]
+        pub to_address: Option<Pubkey>,
+        /// The programs that the activity was executed through.
+        #[serde(default, with = "crate::serde_string::vec")]
+        pub sources: Vec<Pubkey>,
+        /// The platforms that the activity was executed on.
+        #[serde(default, with = "crate::serde_string::vec")]
+        pub platform: Vec<Pubkey>,
+        /// The raw token amounts. Use [`DefiActivity::record`] for the scaled, typed amounts.
+        #[serde(default)]
+        pub routers: Option<DefiRouters>,
+    }
+
+    pub struct DefiRouters {
+        #[serde(default, with = "crate::serde_string::option")]
+        pub token1: Option<Pubkey>,
+        pub token1_decimals: Option<u64>,
+        pub amount1: Option<u64>,
+        #[serde(default, with = "crate::serde_string::option")]
+        pub token2: Option<Pubkey>,
+        pub token2_decimals: Option<u64>,
+        pub amount2: Option<u64>,
+        /// The individual hops of an aggregated swap.
+        #[serde(default)]
+        pub child_routers: Vec<DefiRouters>,
+    }
+
+    #[derive(Copy, Eq, Hash)]
+    pub enum DefiActivityType {
+        #[serde(rename = "ACTIVITY_TOKEN_SWAP")]
+        TokenSwap,
+        #[serde(rename = "ACTIVITY_AGG_TOKEN_SWAP")]
+        AggTokenSwap,
+        #[serde(rename = "ACTIVITY_TOKEN_ADD_LIQ")]
+        TokenAddLiquidity,
+        #[serde(rename = "ACTIVITY_TOKEN_REMOVE_LIQ")]
+        TokenRemoveLiquidity,
+        #[serde(rename = "ACTIVITY_SPL_TOKEN_STAKE")]
+        TokenStake,
+        #[serde(rename = "ACTIVITY_SPL_TOKEN_UNSTAKE")]
+        TokenUnstake,
+        #[serde(rename = "ACTIVITY_TOKEN_DEPOSIT_VAULT")]
+        TokenDepositVault,
+        #[serde(rename = "ACTIVITY_TOKEN_WITHDRAW_VAULT")]
+        TokenWithdrawVault,
+        #[serde(rename = "ACTIVITY_SPL_INIT_MINT")]
+        TokenInitMint,
+        #[serde(rename = "ACTIVITY_ORDERBOOK_ORDER_PLACE")]
+        OrderbookOrderPlace,
+        #[serde(rename = "ACTIVITY_BORROWING")]
+        Borrowing,
+        #[serde(rename = "ACTIVITY_REPAY_BORROWING")]
+        RepayBorrowing,
+        #[serde(rename = "ACTIVITY_LIQUIDATE_BORROWING")]
+        LiquidateBorrowing,
+        /// Any other activity type, serialized and displayed as `ACTIVITY_UNKNOWN`.
+        #[default]
+        #[serde(rename = "ACTIVITY_UNKNOWN", other)]
+        Unknown,
+    }
+}
+
+impl From<DefiActivityType> for &'static str {
+    fn from(val: DefiActivityType) -> Self {
+        use DefiActivityType::*;
+        match val {
+            TokenSwap => "ACTIVITY_TOKEN_SWAP",
+            AggTokenSwap => "ACTIVITY_AGG_TOKEN_SWAP",
+            TokenAddLiquidity => "ACTIVITY_TOKEN_ADD_LIQ",
+            TokenRemoveLiquidity => "ACTIVITY_TOKEN_REMOVE_LIQ",
+            TokenStake => "ACTIVITY_SPL_TOKEN_STAKE",
+            TokenUnstake => "ACTIVITY_SPL_TOKEN_UNSTAKE",
+            TokenDepositVault => "ACTIVITY_TOKEN_DEPOSIT_VAULT",
+            TokenWithdrawVault => "ACTIVITY_TOKEN_WITHDRAW_VAULT",
+            TokenInitMint => "ACTIVITY_SPL_INIT_MINT",
+            OrderbookOrderPlace => "ACTIVITY_ORDERBOOK_ORDER_PLACE",
+            Borrowing => "ACTIVITY_BORROWING",
+            RepayBorrowing => "ACTIVITY_REPAY_BORROWING",
+            LiquidateBorrowing => "ACTIVITY_LIQUIDATE_BORROWING",
+            Unknown => "ACTIVITY_UNKNOWN",
+        }
+    }
+}
+
+impl AsRef<str> for DefiActivityType {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl fmt::Display for DefiActivityType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_ref())
+    }
+}
+
+/// A token amount of a DeFi activity.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DefiTokenAmount {
+    pub token: Pubkey,
+    /// The amount, scaled by the token's decimals.
+    pub amount: RawAmount,
+}
+
+/// A typed DeFi activity record, returned by [`DefiActivity::record`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum DefiRecord {
+    Swap { token_in: DefiTokenAmount, token_out: DefiTokenAmount },
+    AddLiquidity { tokens: Vec<DefiTokenAmount> },
+    RemoveLiquidity { tokens: Vec<DefiTokenAmount> },
+    Borrow(DefiTokenAmount),
+    Repay(DefiTokenAmount),
+    Liquidate { repaid: DefiTokenAmount, seized: Option<DefiTokenAmount> },
+}
+
+impl AccountType {
+    /// Returns whether accounts of this type are executable.
+    pub fn is_executable(&self) -> bool {
+        matches!(self, Self::Program)
+    }
+}
+
+impl AccountKind {
+    /// Returns the account type, without the type-specific data.
+    pub fn account_type(&self) -> AccountType {
+        match self {
+            Self::SystemAccount => AccountType::SystemAccount,
+            Self::TokenAccount(_) => AccountType::TokenAccount,
+            Self::Mint(_) => AccountType::Mint,
+            Self::Program(_) => AccountType::Program,
+            Self::StakeAccount(_) => AccountType::StakeAccount,
+            Self::VoteAccount(_) => AccountType::VoteAccount,
+            Self::Other { r#type } => AccountType::Other(r#type.clone()),
+        }
+    }
+}
+
+impl AsRef<str> for AccountType {
+    fn as_ref(&self) -> &str {
+        use AccountType::*;
+        match self {
+            SystemAccount => "system_account",
+            TokenAccount => "token_account",
+            Mint => "token",
+            Program => "program",
+            StakeAccount => "stake_account",
+            VoteAccount => "vote_account",
+            Other(s) => s,
+        }
+    }
+}
+
+impl fmt::Display for AccountType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_ref())
+    }
+}
+
+impl TokenAmount {
+    /// Returns the lossless amount.
+    pub fn raw_amount(&self) -> std::result::Result<RawAmount, ParseAmountError> {
+        RawAmount::parse_raw(&self.amount, to_decimals(self.decimals)?)
+    }
+}
+
+impl DefiRouters {
+    /// Returns the first token amount, if present.
+    pub fn token1_amount(&self) -> Option<DefiTokenAmount> {
+        DefiTokenAmount::new(self.token1.as_ref()?.to_owned(), self.amount1?, self.token1_decimals?)
+    }
+
+    /// Returns the second token amount, if present.
+    pub fn token2_amount(&self) -> Option<DefiTokenAmount> {
+        DefiTokenAmount::new(self.token2.as_ref()?.to_owned(), self.amount2?, self.token2_decimals?)
+    }
+}
+
+impl DefiTokenAmount {
+    fn new(token: Pubkey, amount: u64, decimals: u64) -> Option<Self> {
+        let amount = to_decimals(decimals).and_then(|d| RawAmount::new(amount as u128, d)).ok()?;
+        Some(Self { token, amount })
+    }
+}
+
+impl DefiActivity {
+    /// Returns the typed swap, liquidity or lending record of this activity.
+    ///
+    /// Returns `None` for any other activity type, or if the token amounts are missing.
+    pub fn record(&self) -> Option<DefiRecord> {
+        use DefiActivityType::*;
+        let routers = self.routers.as_ref()?;
+        Some(match self.activity_type {
+            TokenSwap | AggTokenSwap => DefiRecord::Swap {
+                token_in: routers.token1_amount()?,
+                token_out: routers.token2_amount()?,
+            },
+            TokenAddLiquidity => DefiRecord::AddLiquidity {
+                tokens: routers
+                    .token1_amount()
+                    .into_iter()
+                    .chain(routers.token2_amount())
+                    .collect(),
+            },
+            TokenRemoveLiquidity => DefiRecord::RemoveLiquidity {
+                tokens: routers
+                    .token1_amount()
+                    .into_iter()
+                    .chain(routers.token2_amount())
+                    .collect(),
+            },
+            Borrowing => DefiRecord::Borrow(routers.token1_amount()?),
+            RepayBorrowing => DefiRecord::Repay(routers.token1_amount()?),
+            LiquidateBorrowing => DefiRecord::Liquidate {
+                repaid: routers.token1_amount()?,
+                seized: routers.token2_amount(),
+            },
+            _ => return None,
+        })
+    }
+}
+
+/// Filters for [`Client::account_defi_activities`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DefiActivitiesFilter {
+    pub activity_types: Vec<DefiActivityType>,
+    pub platforms: Vec<Pubkey>,
+    pub sources: Vec<Pubkey>,
+    pub token: Option<Pubkey>,
+    pub from_time: Option<UnixTime>,
+    pub to_time: Option<UnixTime>,
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
+
+impl DefiActivitiesFilter {
+    fn query(&self, account: &Pubkey) -> Vec<(&'static str, String)> {
+        let mut query: Vec<(&str, String)> = Vec::with_capacity(8);
+        query.push(("address", account.to_string()));
+        for activity_type in &self.activity_types {
+            query.push(("activity_type[]", activity_type.to_string()));
+        }
+        for platform in &self.platforms {
+            query.push(("platform[]", platform.to_string()));
+        }
+        for source in &self.sources {
+            query.push(("source[]", source.to_string()));
+        }
+        if let Some(token) = &self.token {
+            query.push(("token", token.to_string()));
+        }
+        if let Some(from_time) = self.from_time {
+            query.push(("from_time", from_time.to_string()));
+        }
+        if let Some(to_time) = self.to_time {
+            query.push(("to_time", to_time.to_string()));
+        }
+        if let Some(page) = self.page {
+            query.push(("page", page.to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            query.push(("page_size", page_size.to_string()));
+        }
+        query
+    }
+}
+
+pro_api_models! {
+    pub struct BalanceChange {
+        pub block_id: u64,
+        #[serde(with = "crate::serde_time")]
+        pub block_time: Timestamp,
+        #[serde(with = "crate::serde_string")]
+        pub trans_id: Signature,
+        #[serde(with = "crate::serde_string")]
+        pub address: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        pub token_address: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        pub token_account: Pubkey,
+        pub token_decimals: u64,
+        /// The absolute change amount, in the token's smallest unit.
+        pub amount: u64,
+        pub pre_balance: u64,
+        pub post_balance: u64,
+        #[serde(rename = "change_type")]
+        pub flow: Flow,
+        pub fee: u64,
+    }
+
+    #[derive(Copy, Eq, Hash)]
+    pub enum Flow {
+        /// The balance increased.
+        #[default]
+        #[serde(rename = "inc", alias = "in")]
+        In,
+        /// The balance decreased.
+        #[serde(rename = "dec", alias = "out")]
+        Out,
+    }
+}
+
+impl From<Flow> for &'static str {
+    fn from(val: Flow) -> Self {
+        match val {
+            Flow::In => "in",
+            Flow::Out => "out",
+        }
+    }
+}
+
+impl AsRef<str> for Flow {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl fmt::Display for Flow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_ref())
+    }
+}
+
+/// Filters for [`Client::account_balance_changes`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct BalanceChangesFilter {
+    pub token: Option<Pubkey>,
+    /// Minimum change amount, in the token's smallest unit.
+    pub min_amount: Option<u64>,
+    /// Maximum change amount, in the token's smallest unit.
+    pub max_amount: Option<u64>,
+    pub from_time: Option<UnixTime>,
+    pub to_time: Option<UnixTime>,
+    pub flow: Option<Flow>,
+    /// Whether to exclude changes of tokens that Solscan flags as spam.
+    pub remove_spam: bool,
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
+
+impl BalanceChangesFilter {
+    fn query(&self, account: &Pubkey) -> Vec<(&'static str, String)> {
+        let mut query: Vec<(&str, String)> = Vec::with_capacity(8);
+        query.push(("address", account.to_string()));
+        if let Some(token) = &self.token {
+            query.push(("token", token.to_string()));
+        }
+        if self.min_amount.is_some() || self.max_amount.is_some() {
+            query.push(("amount[]", self.min_amount.unwrap_or(0).to_string()));
+            query.push(("amount[]", self.max_amount.unwrap_or(u64::MAX).to_string()));
+        }
+        if let Some(from_time) = self.from_time {
+            query.push(("from_time", from_time.to_string()));
+        }
+        if let Some(to_time) = self.to_time {
+            query.push(("to_time", to_time.to_string()));
+        }
+        if let Some(flow) = self.flow {
+            query.push(("flow", flow.to_string()));
+        }
+        if self.remove_spam {
+            query.push(("remove_spam", "true".to_string()));
+        }
+        if let Some(page) = self.page {
+            query.push(("page", page.to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            query.push(("page_size", page_size.to_string()));
+        }
+        query
+    }
+}
+
+pro_api_models! {
+    pub struct Portfolio {
+        /// The total USD value of the native balance and all the priced tokens.
+        pub total_value: f64,
+        pub native_balance: PortfolioNativeBalance,
+        pub tokens: Vec<PortfolioToken>,
+    }
+
+    pub struct PortfolioNativeBalance {
+        /// The balance in lamports.
+        pub amount: u64,
+        /// The balance in SOL.
+        pub balance: f64,
+        /// The USD price of SOL, if known.
+        pub token_price: Option<f64>,
+        /// The USD value of `balance`, if the price is known.
+        pub token_value: Option<f64>,
+        /// The share of [`Portfolio::total_value`], from `0.0` to `1.0`. `None` if the value is not
+        /// known or the portfolio has no value.
+        #[serde(default)]
+        pub share: Option<f64>,
+    }
+
+    pub struct PortfolioToken {
+        #[serde(with = "crate::serde_string")]
+        pub token_address: Pubkey,
+        #[serde(default, with = "crate::serde_string::option")]
+        pub token_account: Option<Pubkey>,
+        pub token_name: Option<String>,
+        pub token_symbol: Option<String>,
+        pub token_icon: Option<String>,
+        pub token_decimals: u64,
+        /// The raw amount, in the token's smallest unit.
+        pub amount: u64,
+        /// The amount scaled by `token_decimals`.
+        pub balance: f64,
+        /// The USD price, if known.
+        pub token_price: Option<f64>,
+        /// The USD value of `balance`, if the price is known.
+        pub token_value: Option<f64>,
+        /// The share of [`Portfolio::total_value`], from `0.0` to `1.0`. `None` if the token has no
+        /// known price or the portfolio has no value.
+        #[serde(default)]
+        pub share: Option<f64>,
+    }
+}
+
+impl BalanceChange {
+    /// Returns the lossless change amount.
+    pub fn raw_amount(&self) -> std::result::Result<RawAmount, ParseAmountError> {
+        RawAmount::new(self.amount as u128, to_decimals(self.token_decimals)?)
+    }
+}
+
+impl PortfolioToken {
+    /// Returns the lossless amount.
+    pub fn raw_amount(&self) -> std::result::Result<RawAmount, ParseAmountError> {
+        RawAmount::new(self.amount as u128, to_decimals(self.token_decimals)?)
+    }
+}
+
+impl Portfolio {
+    /// Sets the `share` of the native balance and of every priced token from
+    /// [`Portfolio::total_value`]. Unpriced holdings are skipped.
+    fn compute_shares(&mut self) {
+        let total_value = self.total_value;
+        let share =
+            |value: Option<f64>| value.filter(|_| total_value > 0.0).map(|v| v / total_value);
+        self.native_balance.share = share(self.native_balance.token_value);
+        for token in &mut self.tokens {
+            token.share = share(token.token_value);
+        }
+    }
+}
+
+pro_api_models! {
+    pub struct TokenAccount {
+        #[serde(with = "crate::serde_string")]
+        pub token_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        pub token_address: Pubkey,
+        /// The raw amount, in the token's smallest unit.
+        pub amount: u64,
+        pub token_decimals: u64,
+        #[serde(with = "crate::serde_string")]
+        pub owner: Pubkey,
+        /// Whether the token is an NFT. Set from the requested [`TokenAccountType`].
+        #[serde(default)]
+        pub is_nft: bool,
+        /// The collection of the NFT, if any.
+        #[serde(default, alias = "nft_collection")]
+        pub collection: Option<NftCollection>,
+    }
+
+    pub struct NftCollection {
+        #[serde(default, with = "crate::serde_string::option")]
+        pub address: Option<Pubkey>,
+        pub name: Option<String>,
+    }
+
+    #[derive(Copy, Eq, Hash)]
+    pub enum TokenAccountType {
+        /// Fungible tokens.
+        #[default]
+        Token,
+        /// Non-fungible tokens.
+        Nft,
+    }
+}
+
+impl From<TokenAccountType> for &'static str {
+    fn from(val: TokenAccountType) -> Self {
+        match val {
+            TokenAccountType::Token => "token",
+            TokenAccountType::Nft => "nft",
+        }
+    }
+}
+
+impl AsRef<str> for TokenAccountType {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl fmt::Display for TokenAccountType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_ref())
+    }
+}
+
+/// Filters for [`Client::account_token_accounts`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TokenAccountsFilter {
+    pub r#type: TokenAccountType,
+    /// Whether to exclude token accounts with a zero balance.
+    pub hide_zero: bool,
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
+
+impl TokenAccountsFilter {
+    fn query(&self, account: &Pubkey) -> Vec<(&'static str, String)> {
+        let mut query: Vec<(&str, String)> = Vec::with_capacity(5);
+        query.push(("address", account.to_string()));
+        query.push(("type", self.r#type.to_string()));
+        if self.hide_zero {
+            query.push(("hide_zero", "true".to_string()));
+        }
+        if let Some(page) = self.page {
+            query.push(("page", page.to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            query.push(("page_size", page_size.to_string()));
+        }
+        query
+    }
+
+    /// Sets [`TokenAccount::is_nft`] from the requested type, as the API does not return it.
+    fn mark_nfts(&self, accounts: &mut [TokenAccount]) {
+        let is_nft = self.r#type == TokenAccountType::Nft;
+        for account in accounts {
+            account.is_nft = is_nft;
+        }
+    }
+}
+
+pro_api_models! {
+    pub struct AccountMetadata {
+        #[serde(with = "crate::serde_string")]
+        pub account_address: Pubkey,
+        pub account_label: Option<String>,
+        pub account_icon: Option<String>,
+        #[serde(default)]
+        pub account_tags: Vec<String>,
+        pub account_type: Option<AccountType>,
+        pub account_domain: Option<String>,
+    }
+}
+
+pro_api_models! {
+    pub struct LeaderboardAccount {
+        #[serde(with = "crate::serde_string")]
+        pub account: Pubkey,
+        /// The USD value of the SOL balance.
+        #[serde(default)]
+        pub sol_values: f64,
+        /// The USD value of the staked SOL.
+        #[serde(default)]
+        pub stake_values: f64,
+        /// The USD value of the token holdings.
+        #[serde(default)]
+        pub token_values: f64,
+        /// The total USD value.
+        #[serde(default)]
+        pub total_values: f64,
+        /// The number of transactions signed by the account.
+        pub total_txs: Option<u64>,
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LeaderboardSortBy {
+    #[default]
+    SolBalance,
+    Stake,
+    TokenValue,
+    TotalValue,
+    Activity,
+}
+
+impl From<LeaderboardSortBy> for &'static str {
+    fn from(val: LeaderboardSortBy) -> Self {
+        use LeaderboardSortBy::*;
+        match val {
+            SolBalance => "sol_values",
+            Stake => "stake_values",
+            TokenValue => "token_values",
+            TotalValue => "total_values",
+            Activity => "total_txs",
+        }
+    }
+}
+
+impl AsRef<str> for LeaderboardSortBy {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl fmt::Display for LeaderboardSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_ref())
+    }
+}
+
 impl From<Account> for crate::solana::Account {
     fn from(value: Account) -> Self {
-        let Account { lamports, owner_program, rent_epoch, .. } = value;
-        Self { lamports, data: vec![], owner: owner_program, executable: false, rent_epoch }
+        let Account { lamports, owner_program, r#type, rent_epoch, .. } = value;
+        let executable = r#type.is_executable();
+        Self { lamports, data: vec![], owner: owner_program, executable, rent_epoch }
     }
 }
 
@@ -95,14 +837,506 @@ impl Client {
     pub async fn account(&self, account: &Pubkey) -> Result<Account> {
         self.get_no_query(&concat_1("account/", &account.to_string())).await
     }
+
+    /// Performs an HTTP `GET` request to the Pro API `/account/detail` path.
+    pub async fn account_detail(&self, account: &Pubkey) -> Result<AccountDetail> {
+        self.get_pro("account/detail", &[("address", account.to_string())]).await
+    }
+
+    /// Performs an HTTP `GET` request to the Pro API `/account/defi/activities` path.
+    pub async fn account_defi_activities(
+        &self,
+        account: &Pubkey,
+        filter: &DefiActivitiesFilter,
+    ) -> Result<Vec<DefiActivity>> {
+        self.get_pro("account/defi/activities", &filter.query(account)).await
+    }
+
+    /// Returns a stream of all the DeFi activities of `account`, starting from `filter.page`.
+    pub fn account_defi_activities_stream<'a>(
+        &'a self,
+        account: &'a Pubkey,
+        filter: DefiActivitiesFilter,
+    ) -> impl Stream<Item = Result<DefiActivity>> + 'a {
+        let page_size = filter.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+        paginate(filter.page.unwrap_or(1), page_size, move |page| {
+            let filter = DefiActivitiesFilter {
+                page: Some(page),
+                page_size: Some(page_size),
+                ..filter.clone()
+            };
+            async move { self.account_defi_activities(account, &filter).await }
+        })
+    }
+
+    /// Performs an HTTP `GET` request to the Pro API `/account/balance_change` path.
+    pub async fn account_balance_changes(
+        &self,
+        account: &Pubkey,
+        filter: &BalanceChangesFilter,
+    ) -> Result<Vec<BalanceChange>> {
+        self.get_pro("account/balance_change", &filter.query(account)).await
+    }
+
+    /// Returns a stream of all the balance changes of `account`, starting from `filter.page`.
+    pub fn account_balance_changes_stream<'a>(
+        &'a self,
+        account: &'a Pubkey,
+        filter: BalanceChangesFilter,
+    ) -> impl Stream<Item = Result<BalanceChange>> + 'a {
+        let page_size = filter.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+        paginate(filter.page.unwrap_or(1), page_size, move |page| {
+            let filter = BalanceChangesFilter {
+                page: Some(page),
+                page_size: Some(page_size),
+                ..filter.clone()
+            };
+            async move { self.account_balance_changes(account, &filter).await }
+        })
+    }
+
+    /// Performs an HTTP `GET` request to the Pro API `/account/portfolio` path.
+    pub async fn account_portfolio(&self, account: &Pubkey) -> Result<Portfolio> {
+        let mut portfolio: Portfolio =
+            self.get_pro("account/portfolio", &[("address", account.to_string())]).await?;
+        portfolio.compute_shares();
+        Ok(portfolio)
+    }
+
+    /// Performs an HTTP `GET` request to the Pro API `/account/token-accounts` path.
+    pub async fn account_token_accounts(
+        &self,
+        account: &Pubkey,
+        filter: &TokenAccountsFilter,
+    ) -> Result<Vec<TokenAccount>> {
+        let mut accounts: Vec<TokenAccount> =
+            self.get_pro("account/token-accounts", &filter.query(account)).await?;
+        filter.mark_nfts(&mut accounts);
+        Ok(accounts)
+    }
+
+    /// Returns a stream of all the token accounts of `account`, starting from `filter.page`.
+    pub fn account_token_accounts_stream<'a>(
+        &'a self,
+        account: &'a Pubkey,
+        filter: TokenAccountsFilter,
+    ) -> impl Stream<Item = Result<TokenAccount>> + 'a {
+        let page_size = filter.page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+        paginate(filter.page.unwrap_or(1), page_size, move |page| {
+            let filter = TokenAccountsFilter {
+                page: Some(page),
+                page_size: Some(page_size),
+                ..filter.clone()
+            };
+            async move { self.account_token_accounts(account, &filter).await }
+        })
+    }
+
+    /// Performs an HTTP `GET` request to the Pro API `/account/metadata` path.
+    pub async fn account_metadata(&self, account: &Pubkey) -> Result<AccountMetadata> {
+        self.get_pro("account/metadata", &[("address", account.to_string())]).await
+    }
+
+    /// Performs an HTTP `GET` request to the Pro API `/account/metadata/multi` path.
+    pub async fn account_metadata_multi(
+        &self,
+        accounts: &[Pubkey],
+    ) -> Result<Vec<AccountMetadata>> {
+        let query: Vec<_> =
+            accounts.iter().map(|account| ("address[]", account.to_string())).collect();
+        self.get_pro("account/metadata/multi", &query).await
+    }
+
+    /// Performs an HTTP `GET` request to the Pro API `/account/leaderboard` path.
+    pub async fn account_leaderboard(
+        &self,
+        sort_by: Option<LeaderboardSortBy>,
+        descending: bool,
+        page: Option<u64>,
+        page_size: Option<u64>,
+    ) -> Result<Vec<LeaderboardAccount>> {
+        self.get_pro(
+            "account/leaderboard",
+            &leaderboard_query(sort_by, descending, page, page_size),
+        )
+        .await
+    }
+
+    /// Returns a stream of the whole account leaderboard.
+    pub fn account_leaderboard_stream(
+        &self,
+        sort_by: Option<LeaderboardSortBy>,
+        descending: bool,
+        page_size: Option<u64>,
+    ) -> impl Stream<Item = Result<LeaderboardAccount>> + '_ {
+        let page_size = page_size.unwrap_or(DEFAULT_PAGE_SIZE);
+        paginate(1, page_size, move |page| {
+            self.account_leaderboard(sort_by, descending, Some(page), Some(page_size))
+        })
+    }
+}
+
+fn leaderboard_query(
+    sort_by: Option<LeaderboardSortBy>,
+    descending: bool,
+    page: Option<u64>,
+    page_size: Option<u64>,
+) -> [(&'static str, String); 4] {
+    [
+        ("sort_by", sort_by.unwrap_or_default().to_string()),
+        ("sort_order", if descending { "desc" } else { "asc" }.to_string()),
+        ("page", page.unwrap_or(1).to_string()),
+        ("page_size", page_size.unwrap_or(10).to_string()),
+    ]
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::solana::Pubkey;
+    use super::*;
 
     static ACCOUNT: &str = "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn";
 
+    #[test]
+    fn test_defi_activity_record() {
+        const SOL: &str = "So11111111111111111111111111111111111111112";
+        const USDC: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+        let activity = |activity_type: &str, routers: serde_json::Value| -> DefiActivity {
+            serde_json::from_value(serde_json::json!({
+                "block_id": 254000000,
+                "trans_id": "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW",
+                "block_time": 1710000000,
+                "activity_type": activity_type,
+                "from_address": ACCOUNT,
+                "sources": ["675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8"],
+                "platform": [],
+                "routers": routers
+            }))
+            .unwrap()
+        };
+        let amount = |token: &str, raw, decimals| DefiTokenAmount {
+            token: token.parse().unwrap(),
+            amount: RawAmount { raw, decimals },
+        };
+
+        let swap = activity(
+            "ACTIVITY_TOKEN_SWAP",
+            serde_json::json!({
+                "token1": SOL, "token1_decimals": 9, "amount1": 1500000000u64,
+                "token2": USDC, "token2_decimals": 6, "amount2": 210000000u64
+            }),
+        );
+        assert_eq!(
+            swap.record(),
+            Some(DefiRecord::Swap {
+                token_in: amount(SOL, 1_500_000_000, 9),
+                token_out: amount(USDC, 210_000_000, 6),
+            })
+        );
+
+        let add_liquidity = activity(
+            "ACTIVITY_TOKEN_ADD_LIQ",
+            serde_json::json!({ "token1": SOL, "token1_decimals": 9, "amount1": 5 }),
+        );
+        assert_eq!(
+            add_liquidity.record(),
+            Some(DefiRecord::AddLiquidity { tokens: vec![amount(SOL, 5, 9)] })
+        );
+
+        let borrow = activity(
+            "ACTIVITY_BORROWING",
+            serde_json::json!({ "token1": USDC, "token1_decimals": 6, "amount1": 1000000 }),
+        );
+        assert_eq!(borrow.record(), Some(DefiRecord::Borrow(amount(USDC, 1_000_000, 6))));
+
+        let missing_routers = activity("ACTIVITY_TOKEN_SWAP", serde_json::Value::Null);
+        assert_eq!(missing_routers.routers, None);
+        assert_eq!(missing_routers.record(), None);
+
+        let missing_amount = activity(
+            "ACTIVITY_TOKEN_SWAP",
+            serde_json::json!({ "token1": SOL, "token1_decimals": 9, "amount1": 5 }),
+        );
+        assert_eq!(missing_amount.record(), None);
+        assert_eq!(activity("ACTIVITY_SPL_TOKEN_STAKE", serde_json::json!({})).record(), None);
+    }
+
+    #[test]
+    fn test_defi_activities_query() {
+        let account: Pubkey = ACCOUNT.parse().unwrap();
+        let filter = DefiActivitiesFilter {
+            activity_types: vec![DefiActivityType::TokenSwap, DefiActivityType::Borrowing],
+            from_time: Some(1_700_000_000.into()),
+            page_size: Some(20),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.query(&account),
+            [
+                ("address", ACCOUNT.to_string()),
+                ("activity_type[]", "ACTIVITY_TOKEN_SWAP".to_string()),
+                ("activity_type[]", "ACTIVITY_BORROWING".to_string()),
+                ("from_time", "1700000000".to_string()),
+                ("page_size", "20".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_defi_activity_type_unknown() {
+        let unknown: DefiActivityType =
+            serde_json::from_value(serde_json::json!("ACTIVITY_SOMETHING_NEW")).unwrap();
+        assert_eq!(unknown, DefiActivityType::Unknown);
+        assert_eq!(serde_json::to_value(unknown).unwrap(), unknown.to_string());
+        assert_eq!(unknown.to_string(), "ACTIVITY_UNKNOWN");
+
+        for ty in [DefiActivityType::TokenSwap, DefiActivityType::LiquidateBorrowing] {
+            assert_eq!(serde_json::to_value(ty).unwrap(), ty.to_string());
+        }
+    }
+
+    #[test]
+    fn test_balance_changes_query() {
+        let account: Pubkey = ACCOUNT.parse().unwrap();
+        let filter = BalanceChangesFilter {
+            min_amount: Some(1_000),
+            flow: Some(Flow::Out),
+            remove_spam: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.query(&account),
+            [
+                ("address", ACCOUNT.to_string()),
+                ("amount[]", "1000".to_string()),
+                ("amount[]", u64::MAX.to_string()),
+                ("flow", "out".to_string()),
+                ("remove_spam", "true".to_string()),
+            ]
+        );
+
+        let filter = BalanceChangesFilter {
+            max_amount: Some(5_000),
+            to_time: Some(1_700_000_000.into()),
+            flow: Some(Flow::In),
+            ..Default::default()
+        };
+        assert_eq!(
+            filter.query(&account),
+            [
+                ("address", ACCOUNT.to_string()),
+                ("amount[]", "0".to_string()),
+                ("amount[]", "5000".to_string()),
+                ("to_time", "1700000000".to_string()),
+                ("flow", "in".to_string()),
+            ]
+        );
+        assert_eq!(
+            BalanceChangesFilter::default().query(&account),
+            [("address", ACCOUNT.to_string())]
+        );
+
+        let flows: Vec<Flow> = serde_json::from_str(r#"["inc", "dec", "in", "out"]"#).unwrap();
+        assert_eq!(flows, [Flow::In, Flow::Out, Flow::In, Flow::Out]);
+        assert_eq!(serde_json::to_string(&Flow::Out).unwrap(), r#""dec""#);
+    }
+
+    #[test]
+    fn test_account_detail_kind() {
+        let detail = |kind: serde_json::Value| -> AccountDetail {
+            let mut json = serde_json::json!({
+                "account": ACCOUNT,
+                "lamports": 2039280,
+                "executable": false,
+                "owner_program": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                "rent_epoch": 361,
+                "is_oncurve": false
+            });
+            json.as_object_mut().unwrap().extend(kind.as_object().unwrap().clone());
+            serde_json::from_value(json).unwrap()
+        };
+
+        let token_account = detail(serde_json::json!({
+            "type": "token_account",
+            "token_address": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+            "owner": "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T",
+            "amount": "1500000",
+            "decimals": 6
+        }));
+        assert_eq!(token_account.lamports, 2039280);
+        let AccountKind::TokenAccount(data) = &token_account.kind else {
+            panic!("{:?}", token_account.kind)
+        };
+        assert_eq!(
+            data.token_address,
+            Some("4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R".parse().unwrap())
+        );
+        assert_eq!(data.amount.as_deref(), Some("1500000"));
+        assert_eq!(data.decimals, Some(6));
+
+        let mint = detail(serde_json::json!({
+            "type": "token",
+            "decimals": 6,
+            "supply": "555000000000000",
+            "mint_authority": null
+        }));
+        let AccountKind::Mint(data) = &mint.kind else { panic!("{:?}", mint.kind) };
+        assert_eq!(data.supply.as_deref(), Some("555000000000000"));
+        assert_eq!(data.mint_authority, None);
+        assert_eq!(mint.kind.account_type(), AccountType::Mint);
+
+        let unknown = detail(serde_json::json!({ "type": "config_account" }));
+        assert_eq!(unknown.kind, AccountKind::Other { r#type: "config_account".to_string() });
+    }
+
+    #[test]
+    fn test_account_kind_other() {
+        let json = serde_json::json!({
+            "account": ACCOUNT,
+            "lamports": 1447680,
+            "type": "nonce_account",
+            "executable": false,
+            "owner_program": "11111111111111111111111111111111",
+            "rent_epoch": 18446744073709551615u64,
+            "is_oncurve": false
+        });
+        let detail: AccountDetail = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(detail.kind, AccountKind::Other { r#type: "nonce_account".to_string() });
+        assert_eq!(detail.kind.account_type(), AccountType::Other("nonce_account".to_string()));
+        assert_eq!(serde_json::to_value(&detail).unwrap(), json);
+    }
+
+    #[test]
+    fn test_token_accounts_query() {
+        let account: Pubkey = ACCOUNT.parse().unwrap();
+        assert_eq!(
+            TokenAccountsFilter::default().query(&account),
+            [("address", ACCOUNT.to_string()), ("type", "token".to_string())]
+        );
+
+        let filter = TokenAccountsFilter {
+            r#type: TokenAccountType::Nft,
+            hide_zero: true,
+            page: Some(2),
+            page_size: Some(40),
+        };
+        assert_eq!(
+            filter.query(&account),
+            [
+                ("address", ACCOUNT.to_string()),
+                ("type", "nft".to_string()),
+                ("hide_zero", "true".to_string()),
+                ("page", "2".to_string()),
+                ("page_size", "40".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_token_accounts_is_nft() {
+        let mut accounts: Vec<TokenAccount> = serde_json::from_value(serde_json::json!([{
+            "token_account": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+            "token_address": "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+            "amount": 1,
+            "token_decimals": 0,
+            "owner": ACCOUNT,
+            "nft_collection": { "name": "Mad Lads" }
+        }]))
+        .unwrap();
+        assert!(!accounts[0].is_nft);
+        assert_eq!(accounts[0].collection.as_ref().unwrap().name.as_deref(), Some("Mad Lads"));
+
+        let filter = TokenAccountsFilter { r#type: TokenAccountType::Nft, ..Default::default() };
+        filter.mark_nfts(&mut accounts);
+        assert!(accounts[0].is_nft);
+
+        TokenAccountsFilter::default().mark_nfts(&mut accounts);
+        assert!(!accounts[0].is_nft);
+    }
+
+    #[test]
+    fn test_leaderboard_query() {
+        assert_eq!(
+            leaderboard_query(None, true, None, None),
+            [
+                ("sort_by", "sol_values".to_string()),
+                ("sort_order", "desc".to_string()),
+                ("page", "1".to_string()),
+                ("page_size", "10".to_string()),
+            ]
+        );
+        assert_eq!(
+            leaderboard_query(Some(LeaderboardSortBy::TokenValue), false, Some(2), Some(100)),
+            [
+                ("sort_by", "token_values".to_string()),
+                ("sort_order", "asc".to_string()),
+                ("page", "2".to_string()),
+                ("page_size", "100".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_portfolio_shares() {
+        let mut portfolio: Portfolio = serde_json::from_value(serde_json::json!({
+            "total_value": 200.0,
+            "native_balance": {
+                "amount": 1000000000,
+                "balance": 1.0,
+                "token_price": 150.0,
+                "token_value": 150.0
+            },
+            "tokens": [
+                {
+                    "token_address": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+                    "token_decimals": 6,
+                    "amount": 25000000,
+                    "balance": 25.0,
+                    "token_price": 2.0,
+                    "token_value": 50.0
+                },
+                {
+                    "token_address": "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn",
+                    "token_decimals": 0,
+                    "amount": 1,
+                    "balance": 1.0
+                }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(portfolio.tokens[1].token_price, None);
+
+        portfolio.compute_shares();
+        assert_eq!(portfolio.native_balance.share, Some(0.75));
+        assert_eq!(portfolio.tokens[0].share, Some(0.25));
+        assert_eq!(portfolio.tokens[1].share, None);
+
+        portfolio.total_value = 0.0;
+        portfolio.compute_shares();
+        assert_eq!(portfolio.native_balance.share, None);
+        assert!(portfolio.tokens.iter().all(|token| token.share.is_none()));
+    }
+
+    #[test]
+    fn test_account_metadata_type() {
+        let metadata = |account_type: &str| -> AccountMetadata {
+            serde_json::from_value(serde_json::json!({
+                "account_address": ACCOUNT,
+                "account_label": "Raydium Authority V4",
+                "account_tags": ["raydium"],
+                "account_type": account_type
+            }))
+            .unwrap()
+        };
+        let program = metadata("program");
+        assert_eq!(program.account_label.as_deref(), Some("Raydium Authority V4"));
+        assert_eq!(program.account_type, Some(AccountType::Program));
+        assert_eq!(metadata("token").account_type, Some(AccountType::Mint));
+        assert_eq!(
+            metadata("nonce_account").account_type,
+            Some(AccountType::Other("nonce_account".to_string()))
+        );
+    }
+
     test_route!(test_account_tokens: |c| c.account_tokens(&ACCOUNT.parse().unwrap()) => |res| {
         assert!(!res.is_empty());
     });
@@ -114,5 +1348,67 @@ mod tests {
     test_route!(test_account: |c| c.account(&ACCOUNT.parse().unwrap()) => |res| {
         assert_eq!(res.account, ACCOUNT.parse::<Pubkey>().unwrap());
         assert_ne!(res.lamports, 0);
+        assert_eq!(res.r#type, super::AccountType::SystemAccount);
     });
+
+    test_route!(
+        #[ignore = "requires SOLSCAN_API_KEY"]
+        test_account_detail: |c| c.account_detail(&ACCOUNT.parse().unwrap()) => |res| {
+            assert_eq!(res.account, ACCOUNT.parse::<Pubkey>().unwrap());
+            assert_eq!(res.kind, super::AccountKind::SystemAccount);
+        }
+    );
+
+    test_route!(
+        #[ignore = "requires SOLSCAN_API_KEY"]
+        test_account_defi_activities: |c| c.account_defi_activities(&ACCOUNT.parse().unwrap(), &Default::default()) => |res| {
+            assert!(!res.is_empty());
+        }
+    );
+
+    test_route!(
+        #[ignore = "requires SOLSCAN_API_KEY"]
+        test_account_balance_changes: |c| c.account_balance_changes(&ACCOUNT.parse().unwrap(), &Default::default()) => |res| {
+            assert!(!res.is_empty());
+        }
+    );
+
+    test_route!(
+        #[ignore = "requires SOLSCAN_API_KEY"]
+        test_account_portfolio: |c| c.account_portfolio(&ACCOUNT.parse().unwrap()) => |res| {
+            assert!(res.total_value > 0.0);
+            let shares = res.native_balance.share.into_iter().chain(res.tokens.iter().filter_map(|t| t.share));
+            assert!(shares.sum::<f64>() <= 1.0 + f64::EPSILON * 100.0);
+        }
+    );
+
+    test_route!(
+        #[ignore = "requires SOLSCAN_API_KEY"]
+        test_account_token_accounts: |c| c.account_token_accounts(&ACCOUNT.parse().unwrap(), &Default::default()) => |res| {
+            assert!(!res.is_empty());
+            assert!(res.iter().all(|account| !account.is_nft));
+        }
+    );
+
+    test_route!(
+        #[ignore = "requires SOLSCAN_API_KEY"]
+        test_account_metadata: |c| c.account_metadata(&ACCOUNT.parse().unwrap()) => |res| {
+            assert_eq!(res.account_address, ACCOUNT.parse::<Pubkey>().unwrap());
+        }
+    );
+
+    test_route!(
+        #[ignore = "requires SOLSCAN_API_KEY"]
+        test_account_metadata_multi: |c| c.account_metadata_multi(&[ACCOUNT.parse().unwrap()]) => |res| {
+            assert_eq!(res.len(), 1);
+        }
+    );
+
+    test_route!(
+        #[ignore = "requires SOLSCAN_API_KEY"]
+        test_account_leaderboard: |c| c.account_leaderboard(None, true, None, Some(10)) => |res| {
+            assert_eq!(res.len(), 10);
+            assert!(res[0].sol_values >= res[9].sol_values);
+        }
+    );
 }
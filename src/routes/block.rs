@@ -2,13 +2,14 @@
 
 use crate::{
     concat_1, solana::Hash, Client, ClientError, ResponseError, ResponseErrorMessage, Result,
-    TransactionInfo,
+    Timestamp, TransactionInfo,
 };
 
 api_models! {
     pub struct BlockInfo {
         pub block_height: Option<u64>,
-        pub block_time: Option<u64>,
+        #[serde(default, with = "crate::serde_time::option")]
+        pub block_time: Option<Timestamp>,
         #[serde(with = "crate::serde_string")]
         pub blockhash: Hash,
         pub fee_rewards: u64,
@@ -12,5 +12,8 @@ pub use account::*;
 mod token;
 pub use token::*;
 
+mod program;
+pub use program::*;
+
 mod other;
 pub use other::*;
@@ -1,7 +1,11 @@
-//! Market, chain information and tools sections.
+//! Market, chain information, tools and monitoring sections.
 
-use crate::{concat_1, solana::Pubkey, Client, Result};
-use serde_json::Value;
+use crate::{
+    concat_1,
+    solana::{Hash, Pubkey},
+    AddressTableLookup, Client, Instruction, MessageHeader, Result, Timestamp,
+    TransactionAccountKey,
+};
 
 api_models! {
     pub struct TokenMarketInfo {
@@ -15,6 +19,140 @@ api_models! {
         pub absolute_slot: u64,
         pub transaction_count: u64,
     }
+
+    pub struct InspectedMessage {
+        pub header: MessageHeader,
+        /// The account keys, with their signer and writable flags.
+        pub account_keys: Vec<TransactionAccountKey>,
+        #[serde(with = "crate::serde_string")]
+        pub recent_blockhash: Hash,
+        pub instructions: Vec<Instruction>,
+        /// The address table lookups of a v0 message.
+        #[serde(default)]
+        pub address_table_lookups: Vec<AddressTableLookup>,
+    }
+}
+
+pro_api_models! {
+    pub struct PoolMarket {
+        #[serde(with = "crate::serde_string")]
+        pub pool_id: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        pub program_id: Pubkey,
+        #[serde(rename = "token_1", with = "crate::serde_string")]
+        pub token1: Pubkey,
+        #[serde(rename = "token_2", with = "crate::serde_string")]
+        pub token2: Pubkey,
+        #[serde(rename = "token_account_1", with = "crate::serde_string")]
+        pub token1_account: Pubkey,
+        #[serde(rename = "token_account_2", with = "crate::serde_string")]
+        pub token2_account: Pubkey,
+        #[serde(default)]
+        pub total_trades_24h: u64,
+        #[serde(default)]
+        pub total_trades_prev_24h: u64,
+        /// The USD volume in the last 24 hours.
+        #[serde(default)]
+        pub total_volume_24h: f64,
+        /// The USD volume in the 24 hours before the last 24 hours.
+        #[serde(default)]
+        pub total_volume_prev_24h: f64,
+    }
+
+    pub struct PoolMarketInfo {
+        #[serde(with = "crate::serde_string")]
+        pub pool_address: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        pub program_id: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        pub token1: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        pub token2: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        pub token1_account: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        pub token2_account: Pubkey,
+        /// The raw reserve of `token1`, in the token's smallest unit.
+        pub token1_amount: u64,
+        /// The raw reserve of `token2`, in the token's smallest unit.
+        pub token2_amount: u64,
+        pub token1_decimals: Option<u64>,
+        pub token2_decimals: Option<u64>,
+        /// The total value locked in USD.
+        pub tvl: Option<f64>,
+        /// The swap fee, as a fraction of the traded amount.
+        pub fee_tier: Option<f64>,
+        #[serde(default, with = "crate::serde_time::option")]
+        pub created_time: Option<Timestamp>,
+    }
+
+    pub struct PoolMarketVolume {
+        #[serde(with = "crate::serde_string")]
+        pub pool_address: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        pub program_id: Pubkey,
+        /// The USD volume in the last 24 hours.
+        #[serde(default)]
+        pub total_volume_24h: f64,
+        /// The relative change of `total_volume_24h` compared to the previous 24 hours.
+        #[serde(default)]
+        pub total_volume_change_24h: f64,
+        #[serde(default)]
+        pub total_trades_24h: u64,
+        #[serde(default)]
+        pub total_trades_change_24h: f64,
+        /// The daily USD volume history.
+        #[serde(default)]
+        pub days: Vec<PoolMarketDailyVolume>,
+    }
+
+    pub struct PoolMarketDailyVolume {
+        /// The day, formatted as `YYYYMMDD`.
+        pub day: u32,
+        pub volume: f64,
+    }
+
+    pub struct ApiUsage {
+        /// The compute units left in the current billing period.
+        pub remaining_cus: u64,
+        /// The compute units used in the current billing period.
+        pub usage_cus: u64,
+        pub total_requests_24h: u64,
+        /// The share of successful requests in the last 24 hours, from `0.0` to `1.0`.
+        pub success_rate_24h: f64,
+        /// The compute units used in the last 24 hours.
+        pub total_cu_24h: u64,
+    }
+}
+
+/// Filters for [`Client::market_list`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MarketsFilter {
+    /// The AMM program the pools belong to.
+    pub program: Option<Pubkey>,
+    /// A token that the pools must contain.
+    pub token: Option<Pubkey>,
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
+
+impl MarketsFilter {
+    fn query(&self) -> Vec<(&'static str, String)> {
+        let mut query: Vec<(&str, String)> = Vec::with_capacity(4);
+        if let Some(program) = &self.program {
+            query.push(("program", program.to_string()));
+        }
+        if let Some(token) = &self.token {
+            query.push(("token_address", token.to_string()));
+        }
+        if let Some(page) = self.page {
+            query.push(("page", page.to_string()));
+        }
+        if let Some(page_size) = self.page_size {
+            query.push(("page_size", page_size.to_string()));
+        }
+        query
+    }
 }
 
 impl Client {
@@ -23,30 +161,175 @@ impl Client {
         self.get_no_query(&concat_1("market/token/", &token.to_string())).await
     }
 
+    /// Performs an HTTP `GET` request to the Pro API `/market/list` path.
+    pub async fn market_list(&self, filter: &MarketsFilter) -> Result<Vec<PoolMarket>> {
+        self.get_pro("market/list", &filter.query()).await
+    }
+
+    /// Performs an HTTP `GET` request to the Pro API `/market/info` path.
+    pub async fn market_info(&self, pool: &Pubkey) -> Result<PoolMarketInfo> {
+        self.get_pro("market/info", &[("address", pool.to_string())]).await
+    }
+
+    /// Performs an HTTP `GET` request to the Pro API `/market/volume` path.
+    pub async fn market_volume(&self, pool: &Pubkey) -> Result<PoolMarketVolume> {
+        self.get_pro("market/volume", &[("address", pool.to_string())]).await
+    }
+
     /// Performs an HTTP `GET` request to the `/chaininfo` path.
     pub async fn chain_info(&self) -> Result<ChainInfo> {
         self.get_no_query("chaininfo").await
     }
 
-    // TODO: Return value
     /// Performs an HTTP `GET` request to the `/tools/inspect` path.
-    pub async fn tools_inspect(&self, message: String) -> Result<Value> {
+    ///
+    /// `message` is the base64-encoded serialized message.
+    pub async fn tools_inspect(&self, message: String) -> Result<InspectedMessage> {
         self.get("tools/inspect", &[("message", message)]).await
     }
+
+    /// Serializes and encodes `message`, and inspects it with [`Client::tools_inspect`].
+    #[cfg(feature = "sdk-full")]
+    pub async fn tools_inspect_message(
+        &self,
+        message: &solana_sdk::message::Message,
+    ) -> Result<InspectedMessage> {
+        use base64::Engine;
+        self.tools_inspect(base64::engine::general_purpose::STANDARD.encode(message.serialize()))
+            .await
+    }
+
+    /// Serializes and encodes `message`, and inspects it with [`Client::tools_inspect`].
+    #[cfg(feature = "sdk-full")]
+    pub async fn tools_inspect_versioned_message(
+        &self,
+        message: &solana_sdk::message::VersionedMessage,
+    ) -> Result<InspectedMessage> {
+        use base64::Engine;
+        self.tools_inspect(base64::engine::general_purpose::STANDARD.encode(message.serialize()))
+            .await
+    }
+
+    /// Performs an HTTP `GET` request to the Pro API `/monitor/usage` path.
+    ///
+    /// Unlike [`Client::credit_usage`], this returns the usage of the API key across all clients.
+    /// It is not charged against the credit budget, so it can be called after the budget is used
+    /// up.
+    pub async fn monitor_usage(&self) -> Result<ApiUsage> {
+        self.get_pro_no_query("monitor/usage").await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ClientError;
+    use crate::{ClientError, UnixTime};
 
     static TOKEN: &str = "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R";
+    static POOL: &str = "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2";
+    static SOL: &str = "So11111111111111111111111111111111111111112";
+
+    #[test]
+    fn test_markets_query() {
+        assert_eq!(MarketsFilter::default().query(), []);
+
+        let filter = MarketsFilter {
+            program: Some("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8".parse().unwrap()),
+            token: Some(TOKEN.parse().unwrap()),
+            page: Some(2),
+            page_size: Some(20),
+        };
+        assert_eq!(
+            filter.query(),
+            [
+                ("program", "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8".to_string()),
+                ("token_address", TOKEN.to_string()),
+                ("page", "2".to_string()),
+                ("page_size", "20".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pool_market() {
+        let json = serde_json::json!({
+            "pool_id": POOL,
+            "program_id": "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8",
+            "token_1": TOKEN,
+            "token_2": SOL,
+            "token_account_1": "DQyrAcCrDXQ7NeoqGgDCZwBvWDcYmFCjSb9JtteuvPpz",
+            "token_account_2": "HLmqeL62xR1QoZ1HKKbXRrdN1p3phKpxRMb2VVopvBBz",
+            "total_trades_24h": 1200,
+            "total_trades_prev_24h": 1000,
+            "total_volume_24h": 250000.5,
+            "total_volume_prev_24h": 200000.0
+        });
+        let market: PoolMarket = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(market.token1, TOKEN.parse::<Pubkey>().unwrap());
+        assert_eq!(market.token2, SOL.parse::<Pubkey>().unwrap());
+        assert_eq!(market.total_trades_24h, 1200);
+        assert_eq!(serde_json::to_value(&market).unwrap(), json);
+    }
+
+    #[test]
+    fn test_pool_market_info() {
+        let info: PoolMarketInfo = serde_json::from_value(serde_json::json!({
+            "pool_address": POOL,
+            "program_id": "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8",
+            "token1": TOKEN,
+            "token2": SOL,
+            "token1_account": "DQyrAcCrDXQ7NeoqGgDCZwBvWDcYmFCjSb9JtteuvPpz",
+            "token2_account": "HLmqeL62xR1QoZ1HKKbXRrdN1p3phKpxRMb2VVopvBBz",
+            "token1_amount": 5000000000u64,
+            "token2_amount": 30000000000u64,
+            "token1_decimals": 6,
+            "tvl": 12345.6,
+            "created_time": 1650000000
+        }))
+        .unwrap();
+        assert_eq!(info.token1, TOKEN.parse::<Pubkey>().unwrap());
+        assert_eq!(info.token1_decimals, Some(6));
+        assert_eq!(info.token2_decimals, None);
+        assert_eq!(info.fee_tier, None);
+        assert_eq!(info.created_time.map(UnixTime::from), Some(UnixTime(1650000000)));
+
+        let volume: PoolMarketVolume = serde_json::from_value(serde_json::json!({
+            "pool_address": POOL,
+            "program_id": "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8",
+            "total_volume_24h": 250000.5,
+            "days": [{ "day": 20240310, "volume": 1000.0 }]
+        }))
+        .unwrap();
+        assert_eq!(volume.total_trades_24h, 0);
+        assert_eq!(volume.days, [PoolMarketDailyVolume { day: 20240310, volume: 1000.0 }]);
+    }
 
     test_route!(test_market: |c| c.market(&TOKEN.parse().unwrap()) => |res| {
         assert!(res.price_usdt.is_normal());
         assert_ne!(res.volume_usdt, 0);
     });
 
+    test_route!(
+        #[ignore = "requires SOLSCAN_API_KEY"]
+        test_market_list: |c| c.market_list(&MarketsFilter { token: Some(TOKEN.parse().unwrap()), ..Default::default() }) => |res| {
+            assert!(!res.is_empty());
+        }
+    );
+
+    test_route!(
+        #[ignore = "requires SOLSCAN_API_KEY"]
+        test_market_info: |c| c.market_info(&POOL.parse().unwrap()) => |res| {
+            assert_eq!(res.pool_address, POOL.parse::<Pubkey>().unwrap());
+        }
+    );
+
+    test_route!(
+        #[ignore = "requires SOLSCAN_API_KEY"]
+        test_market_volume: |c| c.market_volume(&POOL.parse().unwrap()) => |res| {
+            assert_eq!(res.pool_address, POOL.parse::<Pubkey>().unwrap());
+        }
+    );
+
     test_route!(test_chain_info: |c| c.chain_info() => |res| {
         assert!(res.block_height > 156339814);
     });
@@ -62,4 +345,23 @@ mod tests {
         let ClientError::Response(err) = err else { panic!("{err}"); };
         assert_eq!(err.status, 500);
     }
+
+    #[cfg(feature = "sdk-full")]
+    test_route!(test_tools_inspect_message: |c| {
+        let from: Pubkey = "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn".parse().unwrap();
+        let ix = solana_sdk::system_instruction::transfer(&from, &TOKEN.parse().unwrap(), 1);
+        let message = solana_sdk::message::Message::new(&[ix], Some(&from));
+        async move { c.tools_inspect_message(&message).await }
+    } => |res| {
+        assert_eq!(res.header.num_required_signatures, 1);
+        assert_eq!(res.account_keys.len(), 3);
+        assert!(res.account_keys[0].signer);
+    });
+
+    test_route!(
+        #[ignore = "requires SOLSCAN_API_KEY"]
+        test_monitor_usage: |c| c.monitor_usage() => |res| {
+            assert!(res.success_rate_24h <= 1.0);
+        }
+    );
 }
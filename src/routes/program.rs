@@ -0,0 +1,253 @@
+//! Program section.
+
+use crate::{solana::Pubkey, Client, Result, Timestamp};
+use std::{collections::HashMap, fmt};
+
+pro_api_models! {
+    pub struct ProgramInfo {
+        #[serde(with = "crate::serde_string")]
+        pub program_id: Pubkey,
+        pub name: Option<String>,
+        #[serde(default)]
+        pub num_txs: u64,
+        #[serde(default)]
+        pub num_txs_success: u64,
+        #[serde(default)]
+        pub num_active_users: u64,
+        /// The USD volume of the token transfers that interacted with the program.
+        #[serde(default)]
+        pub interaction_volume: f64,
+        /// The share of successful transactions, from `0.0` to `1.0`.
+        #[serde(default)]
+        pub success_rate: f64,
+        #[serde(default, with = "crate::serde_time::option")]
+        pub last_active_time: Option<Timestamp>,
+    }
+
+    pub struct PopularPlatform {
+        pub platform_id: String,
+        pub name: String,
+        pub icon: Option<String>,
+        pub website: Option<String>,
+        /// The programs that the platform is made of.
+        #[serde(default, with = "crate::serde_string::vec")]
+        pub program_ids: Vec<Pubkey>,
+    }
+
+    pub struct ProgramAnalytics {
+        #[serde(with = "crate::serde_string")]
+        pub program_id: Pubkey,
+        /// The daily analytics, oldest first.
+        #[serde(default)]
+        pub days: Vec<ProgramDailyAnalytics>,
+    }
+
+    pub struct ProgramDailyAnalytics {
+        /// The day, formatted as `YYYYMMDD`.
+        pub day: u32,
+        #[serde(default)]
+        pub num_txs: u64,
+        #[serde(default)]
+        pub num_txs_success: u64,
+        #[serde(default)]
+        pub num_active_users: u64,
+        /// The share of successful transactions, from `0.0` to `1.0`.
+        #[serde(default)]
+        pub success_rate: f64,
+    }
+}
+
+impl PopularPlatform {
+    /// Indexes `platforms` by their program IDs, e.g. to name the `program_id` of a
+    /// [`ParsedInstruction`](crate::ParsedInstruction).
+    pub fn by_program(platforms: &[Self]) -> HashMap<&Pubkey, &Self> {
+        platforms
+            .iter()
+            .flat_map(|platform| platform.program_ids.iter().map(move |id| (id, platform)))
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ProgramSortBy {
+    #[default]
+    NumTxs,
+    NumActiveUsers,
+    InteractionVolume,
+    SuccessRate,
+}
+
+impl From<ProgramSortBy> for &'static str {
+    fn from(val: ProgramSortBy) -> Self {
+        use ProgramSortBy::*;
+        match val {
+            NumTxs => "num_txs",
+            NumActiveUsers => "num_active_users",
+            InteractionVolume => "interaction_volume",
+            SuccessRate => "success_rate",
+        }
+    }
+}
+
+impl AsRef<str> for ProgramSortBy {
+    fn as_ref(&self) -> &str {
+        (*self).into()
+    }
+}
+
+impl fmt::Display for ProgramSortBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_ref())
+    }
+}
+
+impl Client {
+    /// Performs an HTTP `GET` request to the Pro API `/program/list` path.
+    pub async fn program_list(
+        &self,
+        sort_by: Option<ProgramSortBy>,
+        descending: bool,
+        page: Option<u64>,
+        page_size: Option<u64>,
+    ) -> Result<Vec<ProgramInfo>> {
+        self.get_pro("program/list", &program_list_query(sort_by, descending, page, page_size))
+            .await
+    }
+
+    /// Performs an HTTP `GET` request to the Pro API `/program/popular/platforms` path.
+    pub async fn program_popular_platforms(&self) -> Result<Vec<PopularPlatform>> {
+        self.get_pro_no_query("program/popular/platforms").await
+    }
+
+    /// Performs an HTTP `GET` request to the Pro API `/program/analytics` path.
+    ///
+    /// `days` is the size of the time window, either `7` (default) or `30`.
+    pub async fn program_analytics(
+        &self,
+        program: &Pubkey,
+        days: Option<u64>,
+    ) -> Result<ProgramAnalytics> {
+        self.get_pro("program/analytics", &program_analytics_query(program, days)).await
+    }
+}
+
+fn program_list_query(
+    sort_by: Option<ProgramSortBy>,
+    descending: bool,
+    page: Option<u64>,
+    page_size: Option<u64>,
+) -> [(&'static str, String); 4] {
+    [
+        ("sort_by", sort_by.unwrap_or_default().to_string()),
+        ("sort_order", if descending { "desc" } else { "asc" }.to_string()),
+        ("page", page.unwrap_or(1).to_string()),
+        ("page_size", page_size.unwrap_or(10).to_string()),
+    ]
+}
+
+fn program_analytics_query(program: &Pubkey, days: Option<u64>) -> [(&'static str, String); 2] {
+    [("address", program.to_string()), ("range", days.unwrap_or(7).to_string())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UnixTime;
+
+    static PROGRAM: &str = "675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8";
+
+    #[test]
+    fn test_popular_platforms_by_program() {
+        let platforms: Vec<PopularPlatform> = serde_json::from_value(serde_json::json!([
+            {
+                "platform_id": "raydium",
+                "name": "Raydium",
+                "website": "https://raydium.io",
+                "program_ids": [PROGRAM, "CAMMCzo5YL8w4VFF8KVHrK22GGUsp5VTaW7grrKgrWqK"]
+            },
+            { "platform_id": "unknown", "name": "Unknown" }
+        ]))
+        .unwrap();
+        assert_eq!(platforms[0].program_ids.len(), 2);
+        assert_eq!(platforms[1].program_ids, []);
+
+        let by_program = PopularPlatform::by_program(&platforms);
+        assert_eq!(by_program.len(), 2);
+        let program: Pubkey = PROGRAM.parse().unwrap();
+        assert_eq!(by_program[&program].name, "Raydium");
+    }
+
+    #[test]
+    fn test_program_info() {
+        let info: ProgramInfo = serde_json::from_value(serde_json::json!({
+            "program_id": PROGRAM,
+            "name": "Raydium Liquidity Pool V4",
+            "num_txs": 1000,
+            "num_txs_success": 900,
+            "success_rate": 0.9,
+            "last_active_time": 1710000000
+        }))
+        .unwrap();
+        assert_eq!(info.num_active_users, 0);
+        assert_eq!(info.success_rate, 0.9);
+        assert_eq!(info.last_active_time.map(UnixTime::from), Some(UnixTime(1710000000)));
+
+        let analytics: ProgramAnalytics = serde_json::from_value(serde_json::json!({
+            "program_id": PROGRAM,
+            "days": [{ "day": 20240310, "num_txs": 10, "num_active_users": 3 }]
+        }))
+        .unwrap();
+        assert_eq!(analytics.days[0].day, 20240310);
+        assert_eq!(analytics.days[0].num_txs_success, 0);
+    }
+
+    #[test]
+    fn test_program_queries() {
+        assert_eq!(
+            program_list_query(None, true, None, None),
+            [
+                ("sort_by", "num_txs".to_string()),
+                ("sort_order", "desc".to_string()),
+                ("page", "1".to_string()),
+                ("page_size", "10".to_string()),
+            ]
+        );
+        assert_eq!(
+            program_list_query(Some(ProgramSortBy::SuccessRate), false, Some(3), Some(20)),
+            [
+                ("sort_by", "success_rate".to_string()),
+                ("sort_order", "asc".to_string()),
+                ("page", "3".to_string()),
+                ("page_size", "20".to_string()),
+            ]
+        );
+
+        let program: Pubkey = PROGRAM.parse().unwrap();
+        assert_eq!(
+            program_analytics_query(&program, None),
+            [("address", PROGRAM.to_string()), ("range", "7".to_string())]
+        );
+        assert_eq!(program_analytics_query(&program, Some(30))[1], ("range", "30".to_string()));
+    }
+
+    test_route!(
+        #[ignore = "requires SOLSCAN_API_KEY"]
+        test_program_list: |c| c.program_list(None, true, None, Some(10)) => |res| {
+            assert_eq!(res.len(), 10);
+        }
+    );
+
+    test_route!(
+        #[ignore = "requires SOLSCAN_API_KEY"]
+        test_program_popular_platforms: |c| c.program_popular_platforms() => |res| {
+            assert!(!res.is_empty());
+        }
+    );
+
+    test_route!(
+        #[ignore = "requires SOLSCAN_API_KEY"]
+        test_program_analytics: |c| c.program_analytics(&PROGRAM.parse().unwrap(), None) => |res| {
+            assert_eq!(res.program_id, PROGRAM.parse::<Pubkey>().unwrap());
+        }
+    );
+}
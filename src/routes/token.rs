@@ -1,6 +1,8 @@
 //! Token section.
 
-use crate::{solana::Pubkey, Client, Result};
+use crate::{
+    amount::to_decimals, solana::Pubkey, Client, Date, ParseAmountError, RawAmount, Result,
+};
 use std::{collections::HashMap, fmt};
 
 api_models! {
@@ -26,7 +28,24 @@ api_models! {
         #[serde(with = "crate::serde_string::option")]
         pub token_authority: Option<Pubkey>,
         pub supply: String,
-        pub r#type: String,
+        pub r#type: TokenType,
+    }
+
+    #[derive(Eq, Hash)]
+    pub enum TokenType {
+        /// An SPL Token fungible token.
+        #[default]
+        #[serde(rename = "token_address", alias = "fungible")]
+        Fungible,
+        /// A non-fungible token.
+        #[serde(rename = "nft", alias = "non_fungible")]
+        Nft,
+        /// A Token-2022 token.
+        #[serde(rename = "token_2022", alias = "token2022")]
+        Token2022,
+        /// Any other token type.
+        #[serde(untagged)]
+        Other(String),
     }
 
     pub struct TokenHolderData {
@@ -53,7 +72,7 @@ api_models! {
         pub id: String,
         #[serde(with = "crate::serde_string")]
         pub address: Pubkey,
-        pub created_at: String,
+        pub created_at: Date,
         pub decimals: u64,
         /// Any metadata extension. Should always contain `"coingeckoId"`.
         pub extensions: HashMap<String, String>,
@@ -62,7 +81,7 @@ api_models! {
         pub market_cap_rank: u64,
         pub mint_address: String,
         pub symbol_has_lower: bool,
-        pub updated_at: String,
+        pub updated_at: Date,
         pub holder: u64,
         #[serde(rename = "marketCapFD")]
         pub market_cap_fd: Option<f64>,
@@ -78,10 +97,10 @@ api_models! {
         pub current_price: f64,
         pub ath: f64,
         pub ath_change_percentage: f64,
-        pub ath_date: String,
+        pub ath_date: Date,
         pub atl: f64,
         pub atl_change_percentage: f64,
-        pub atl_date: String,
+        pub atl_date: Date,
         pub market_cap: f64,
         pub market_cap_rank: Option<u64>,
         pub fully_diluted_valuation: Option<f64>,
@@ -101,7 +120,38 @@ api_models! {
         pub total_supply: Option<f64>,
         pub max_supply: Option<f64>,
         pub circulating_supply: Option<f64>,
-        pub last_updated: String,
+        pub last_updated: Date,
+    }
+}
+
+impl TokenMeta {
+    /// Returns the lossless total supply.
+    pub fn raw_supply(&self) -> std::result::Result<RawAmount, ParseAmountError> {
+        RawAmount::parse_raw(&self.supply, to_decimals(self.decimals)?)
+    }
+}
+
+impl AsRef<str> for TokenType {
+    fn as_ref(&self) -> &str {
+        match self {
+            TokenType::Fungible => "token_address",
+            TokenType::Nft => "nft",
+            TokenType::Token2022 => "token_2022",
+            TokenType::Other(s) => s,
+        }
+    }
+}
+
+impl fmt::Display for TokenType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(self.as_ref())
+    }
+}
+
+impl TokenHolderData {
+    /// Returns the lossless amount.
+    pub fn raw_amount(&self) -> std::result::Result<RawAmount, ParseAmountError> {
+        RawAmount::new(self.amount as u128, to_decimals(self.decimals)?)
     }
 }
 
@@ -199,6 +249,7 @@ impl Client {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::solana::Pubkey;
 
     static TOKEN: &str = "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R";
@@ -212,8 +263,28 @@ mod tests {
         assert_eq!(res.address, TOKEN.parse::<Pubkey>().unwrap());
         assert_eq!(res.name, "Raydium");
         assert_eq!(res.symbol, "RAY");
+        assert_eq!(res.r#type, TokenType::Fungible);
     });
 
+    #[test]
+    fn test_token_type() {
+        let types: Vec<TokenType> =
+            serde_json::from_str(r#"["token_address", "fungible", "nft", "token2022", "spl-new"]"#)
+                .unwrap();
+        assert_eq!(
+            types,
+            [
+                TokenType::Fungible,
+                TokenType::Fungible,
+                TokenType::Nft,
+                TokenType::Token2022,
+                TokenType::Other("spl-new".to_string()),
+            ]
+        );
+        assert_eq!(serde_json::to_string(&types[4]).unwrap(), r#""spl-new""#);
+        assert_eq!(types[3].to_string(), "token_2022");
+    }
+
     test_route!(test_token_list: |c| c.token_list(None, true, Some(100), None) => |res| {
         assert_eq!(res.data.len(), 100);
         assert!(res.total > 1000);
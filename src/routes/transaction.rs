@@ -1,11 +1,15 @@
 //! Transaction section.
 
 use crate::{
+    amount::to_decimals,
     concat_1,
     solana::{Hash, Pubkey, Signature},
-    Client, Result,
+    AccountMetadata, Client, ParseAmountError, ParsedInstructionKind, RawAmount, Result, Timestamp,
+    TokenAmount,
 };
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 
 api_models! {
     pub struct TransactionInfo {
@@ -13,20 +17,32 @@ api_models! {
         pub meta: Option<TransactionMeta>,
         pub transaction: Transaction,
         #[serde(default)]
-        pub version: Option<String>,
+        pub version: Option<TransactionVersion>,
     }
 
     pub struct TransactionMeta {
-        pub err: Option<Value>,
+        pub err: Option<TransactionError>,
         pub fee: Option<u64>,
-        pub inner_instructions: Vec<Value>,
+        pub inner_instructions: Vec<InnerInstructions>,
         pub log_messages: Vec<String>,
         pub post_balances: Vec<u64>,
-        pub post_token_balances: Vec<u64>,
+        pub post_token_balances: Vec<TokenBalance>,
         pub pre_balances: Vec<u64>,
-        pub pre_token_balances: Vec<u64>,
+        pub pre_token_balances: Vec<TokenBalance>,
         pub rewards: Option<Value>,
-        pub status: Option<Value>,
+        pub status: Option<std::result::Result<(), TransactionError>>,
+    }
+
+    pub struct TokenBalance {
+        /// The index of the token account in the message account keys.
+        pub account_index: u8,
+        #[serde(with = "crate::serde_string")]
+        pub mint: Pubkey,
+        #[serde(default, with = "crate::serde_string::option")]
+        pub owner: Option<Pubkey>,
+        #[serde(default, with = "crate::serde_string::option")]
+        pub program_id: Option<Pubkey>,
+        pub ui_token_amount: TokenAmount,
     }
 
     pub struct Transaction {
@@ -37,8 +53,10 @@ api_models! {
 
     pub struct TransactionMessage {
         pub account_keys: Vec<TransactionAccountKey>,
-        pub address_table_lookups: Option<Value>,
-        pub instructions: Vec<Value>,
+        /// The address lookup tables of a `v0` message.
+        #[serde(default)]
+        pub address_table_lookups: Option<Vec<AddressTableLookup>>,
+        pub instructions: Vec<Instruction>,
         #[serde(with = "crate::serde_string")]
         pub recent_blockhash: Hash,
     }
@@ -51,14 +69,64 @@ api_models! {
         pub writable: bool,
     }
 
+    pub struct MessageHeader {
+        pub num_required_signatures: u8,
+        pub num_readonly_signed_accounts: u8,
+        pub num_readonly_unsigned_accounts: u8,
+    }
+
+    pub struct AddressTableLookup {
+        /// The address lookup table account.
+        #[serde(with = "crate::serde_string")]
+        pub account_key: Pubkey,
+        pub writable_indexes: Vec<u8>,
+        pub readonly_indexes: Vec<u8>,
+    }
+
+    pub struct InnerInstructions {
+        /// The index of the top-level instruction that invoked these instructions.
+        pub index: u8,
+        pub instructions: Vec<Instruction>,
+    }
+
+    pub struct CompiledInstruction {
+        /// The index of the program ID in the message account keys.
+        pub program_id_index: u8,
+        /// The indices of the accounts in the message account keys.
+        pub accounts: Vec<u8>,
+        /// The base58-encoded instruction data.
+        pub data: String,
+        pub stack_height: Option<u32>,
+    }
+
+    pub struct PartiallyDecodedInstruction {
+        #[serde(with = "crate::serde_string")]
+        pub program_id: Pubkey,
+        #[serde(with = "crate::serde_string::vec")]
+        pub accounts: Vec<Pubkey>,
+        /// The base58-encoded instruction data.
+        pub data: String,
+        pub stack_height: Option<u32>,
+    }
+
+    pub struct JsonParsedInstruction {
+        /// The name of the program, e.g. `spl-token`.
+        pub program: String,
+        #[serde(with = "crate::serde_string")]
+        pub program_id: Pubkey,
+        pub parsed: Value,
+        pub stack_height: Option<u32>,
+    }
+
     // So many typos
     pub struct GetTransactionInfo {
-        pub block_time: u64,
+        #[serde(with = "crate::serde_time")]
+        pub block_time: Timestamp,
         pub slot: u64,
         #[serde(with = "crate::serde_string")]
         pub tx_hash: Signature,
         pub fee: u64,
-        pub status: String,
+        pub status: TxStatus,
         #[serde(rename = "lamport")]
         pub lamports: u64,
         #[serde(rename = "signer", with = "crate::serde_string::vec")]
@@ -71,16 +139,155 @@ api_models! {
         pub recent_blockhash: Hash,
         pub inner_instructions: Vec<Value>,
         #[serde(rename = "tokenBalanes")]
-        pub token_balances: Vec<Value>,
+        pub token_balances: Vec<TransactionTokenBalance>,
         #[serde(rename = "parsedInstruction")]
-        pub parsed_instructions: Vec<Value>,
+        pub parsed_instructions: Vec<TransactionParsedInstruction>,
         pub confirmations: Option<u64>,
         pub version: String,
-        pub token_transfers: Vec<Value>,
-        pub sol_transfers: Vec<Value>,
-        pub serum_transactions: Vec<Value>,
-        pub raydium_transactions: Vec<Value>,
-        pub unknown_transfers: Vec<Value>,
+        pub token_transfers: Vec<TokenTransfer>,
+        pub sol_transfers: Vec<SolTransfer>,
+        pub serum_transactions: Vec<DexTransaction>,
+        pub raydium_transactions: Vec<DexTransaction>,
+        pub unknown_transfers: Vec<UnknownTransfer>,
+    }
+
+    pub struct TransactionTokenBalance {
+        /// The token account.
+        #[serde(with = "crate::serde_string")]
+        pub account: Pubkey,
+        pub amount: TokenBalanceChange,
+        pub token: TokenBalanceToken,
+    }
+
+    pub struct TokenBalanceChange {
+        /// The raw amount before the transaction, in the token's smallest unit.
+        pub pre_amount: String,
+        /// The raw amount after the transaction, in the token's smallest unit.
+        pub post_amount: String,
+    }
+
+    pub struct TokenBalanceToken {
+        #[serde(with = "crate::serde_string")]
+        pub token_address: Pubkey,
+        pub decimals: u64,
+        pub symbol: Option<String>,
+        pub name: Option<String>,
+        pub icon: Option<String>,
+    }
+
+    pub struct TokenTransfer {
+        #[serde(with = "crate::serde_string")]
+        pub source: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        pub destination: Pubkey,
+        #[serde(rename = "source_owner", default, with = "crate::serde_string::option")]
+        pub source_owner: Option<Pubkey>,
+        #[serde(rename = "destination_owner", default, with = "crate::serde_string::option")]
+        pub destination_owner: Option<Pubkey>,
+        /// The raw amount, in the token's smallest unit.
+        pub amount: String,
+        pub token: TransferToken,
+        pub r#type: Option<String>,
+    }
+
+    pub struct TransferToken {
+        /// The mint address.
+        #[serde(with = "crate::serde_string")]
+        pub address: Pubkey,
+        pub decimals: u64,
+        pub symbol: Option<String>,
+        pub icon: Option<String>,
+    }
+
+    pub struct SolTransfer {
+        #[serde(with = "crate::serde_string")]
+        pub source: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        pub destination: Pubkey,
+        #[serde(rename = "amount")]
+        pub lamports: u64,
+    }
+
+    pub struct UnknownTransfer {
+        #[serde(with = "crate::serde_string")]
+        pub program_id: Pubkey,
+        #[serde(rename = "event")]
+        pub events: Vec<TransferEvent>,
+    }
+
+    pub struct TransferEvent {
+        #[serde(with = "crate::serde_string")]
+        pub source: Pubkey,
+        #[serde(with = "crate::serde_string")]
+        pub destination: Pubkey,
+        #[serde(rename = "source_owner", default, with = "crate::serde_string::option")]
+        pub source_owner: Option<Pubkey>,
+        #[serde(rename = "destination_owner", default, with = "crate::serde_string::option")]
+        pub destination_owner: Option<Pubkey>,
+        /// The mint address, or `None` for SOL transfers.
+        #[serde(default, with = "crate::serde_string::option")]
+        pub token_address: Option<Pubkey>,
+        /// The raw amount, in the token's smallest unit or in lamports.
+        pub amount: Option<u64>,
+        pub decimals: Option<u64>,
+        pub symbol: Option<String>,
+        pub r#type: Option<String>,
+    }
+
+    pub struct TransactionParsedInstruction {
+        #[serde(with = "crate::serde_string")]
+        pub program_id: Pubkey,
+        /// The name of the program, e.g. `spl-token`.
+        pub program: Option<String>,
+        pub r#type: Option<String>,
+        /// The parsed instruction arguments and accounts.
+        #[serde(default)]
+        pub params: Value,
+        /// The base58-encoded instruction data, if the instruction was not parsed.
+        pub data: Option<String>,
+    }
+
+    pub struct DexSwap {
+        /// The pool or market address.
+        #[serde(alias = "ammId", alias = "market", with = "crate::serde_string")]
+        pub pool: Pubkey,
+        #[serde(alias = "owner", with = "crate::serde_string")]
+        pub user: Pubkey,
+        pub token_in: DexTokenAmount,
+        pub token_out: DexTokenAmount,
+    }
+
+    pub struct DexLiquidity {
+        /// The pool address.
+        #[serde(alias = "ammId", with = "crate::serde_string")]
+        pub pool: Pubkey,
+        #[serde(alias = "owner", with = "crate::serde_string")]
+        pub user: Pubkey,
+        pub token_a: DexTokenAmount,
+        pub token_b: DexTokenAmount,
+        /// The LP tokens minted on deposit or burned on withdrawal.
+        pub lp_token: Option<DexTokenAmount>,
+    }
+
+    pub struct DexOrder {
+        /// The market address.
+        #[serde(with = "crate::serde_string")]
+        pub market: Pubkey,
+        #[serde(alias = "owner", with = "crate::serde_string")]
+        pub user: Pubkey,
+        /// `buy` or `sell`.
+        pub side: Option<String>,
+        pub price: Option<f64>,
+        pub size: Option<DexTokenAmount>,
+    }
+
+    pub struct DexTokenAmount {
+        #[serde(with = "crate::serde_string")]
+        pub token_address: Pubkey,
+        /// The raw amount, in the token's smallest unit.
+        pub amount: String,
+        pub decimals: u64,
+        pub symbol: Option<String>,
     }
 
     pub struct TransactionInputAccount {
@@ -93,29 +300,753 @@ api_models! {
     }
 }
 
-#[cfg(feature = "sdk-full")]
-impl From<TransactionInfo> for solana_sdk::transaction::Transaction {
-    fn from(value: TransactionInfo) -> Self {
-        value.transaction.into()
+/// The status of a transaction, as returned by the Solscan API.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum TxStatus {
+    #[serde(alias = "success")]
+    Success,
+    #[serde(alias = "fail", alias = "Failed", alias = "failed")]
+    Fail,
+    /// Any other status.
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+impl TxStatus {
+    /// Returns `true` if the transaction succeeded.
+    pub fn is_success(&self) -> bool {
+        *self == Self::Success
     }
+
+    /// Returns `true` if the transaction failed.
+    pub fn is_fail(&self) -> bool {
+        *self == Self::Fail
+    }
+}
+
+/// Mirrors [`solana_sdk::transaction::TransactionError`](https://docs.rs/solana-sdk/latest/solana_sdk/transaction/enum.TransactionError.html).
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TransactionError {
+    AccountInUse,
+    AccountLoadedTwice,
+    AccountNotFound,
+    ProgramAccountNotFound,
+    InsufficientFundsForFee,
+    InvalidAccountForFee,
+    AlreadyProcessed,
+    BlockhashNotFound,
+    /// An error in the instruction at the given index.
+    InstructionError(u8, InstructionError),
+    CallChainTooDeep,
+    MissingSignatureForFee,
+    InvalidAccountIndex,
+    SignatureFailure,
+    InvalidProgramForExecution,
+    SanitizeFailure,
+    ClusterMaintenance,
+    AccountBorrowOutstanding,
+    WouldExceedMaxBlockCostLimit,
+    UnsupportedVersion,
+    InvalidWritableAccount,
+    WouldExceedMaxAccountCostLimit,
+    WouldExceedAccountDataBlockLimit,
+    TooManyAccountLocks,
+    AddressLookupTableNotFound,
+    InvalidAddressLookupTableOwner,
+    InvalidAddressLookupTableData,
+    InvalidAddressLookupTableIndex,
+    InvalidRentPayingAccount,
+    WouldExceedMaxVoteCostLimit,
+    WouldExceedAccountDataTotalLimit,
+    DuplicateInstruction(u8),
+    InsufficientFundsForRent {
+        account_index: u8,
+    },
+    MaxLoadedAccountsDataSizeExceeded,
+    InvalidLoadedAccountsDataSizeLimit,
+    ResanitizationNeeded,
+    ProgramExecutionTemporarilyRestricted {
+        account_index: u8,
+    },
+    UnbalancedTransaction,
+    /// Any other error.
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+/// Mirrors [`solana_sdk::instruction::InstructionError`](https://docs.rs/solana-sdk/latest/solana_sdk/instruction/enum.InstructionError.html).
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum InstructionError {
+    GenericError,
+    InvalidArgument,
+    InvalidInstructionData,
+    InvalidAccountData,
+    AccountDataTooSmall,
+    InsufficientFunds,
+    IncorrectProgramId,
+    MissingRequiredSignature,
+    AccountAlreadyInitialized,
+    UninitializedAccount,
+    UnbalancedInstruction,
+    ModifiedProgramId,
+    ExternalAccountLamportSpend,
+    ExternalAccountDataModified,
+    ReadonlyLamportChange,
+    ReadonlyDataModified,
+    DuplicateAccountIndex,
+    ExecutableModified,
+    RentEpochModified,
+    NotEnoughAccountKeys,
+    AccountDataSizeChanged,
+    AccountNotExecutable,
+    AccountBorrowFailed,
+    AccountBorrowOutstanding,
+    DuplicateAccountOutOfSync,
+    /// A program-specific error code.
+    Custom(u32),
+    InvalidError,
+    ExecutableDataModified,
+    ExecutableLamportChange,
+    ExecutableAccountNotRentExempt,
+    UnsupportedProgramId,
+    CallDepth,
+    MissingAccount,
+    ReentrancyNotAllowed,
+    MaxSeedLengthExceeded,
+    InvalidSeeds,
+    InvalidRealloc,
+    ComputationalBudgetExceeded,
+    PrivilegeEscalation,
+    ProgramEnvironmentSetupFailure,
+    ProgramFailedToComplete,
+    ProgramFailedToCompile,
+    Immutable,
+    IncorrectAuthority,
+    BorshIoError(String),
+    AccountNotRentExempt,
+    InvalidAccountOwner,
+    ArithmeticOverflow,
+    UnsupportedSysvar,
+    IllegalOwner,
+    MaxAccountsDataAllocationsExceeded,
+    MaxAccountsExceeded,
+    MaxInstructionTraceLengthExceeded,
+    BuiltinProgramsMustConsumeComputeUnits,
+    /// Any other error.
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+impl TransactionError {
+    /// Returns the index of the failed instruction and the custom program error code, if the
+    /// transaction failed with [`InstructionError::Custom`].
+    pub fn custom_error(&self) -> Option<(u8, u32)> {
+        match self {
+            Self::InstructionError(index, InstructionError::Custom(code)) => Some((*index, *code)),
+            _ => None,
+        }
+    }
+}
+
+// Both types share the same serde representation, so any variant known to the SDK converts.
+#[cfg(feature = "sdk")]
+impl TryFrom<TransactionError> for solana_sdk::transaction::TransactionError {
+    type Error = TransactionError;
+
+    fn try_from(value: TransactionError) -> std::result::Result<Self, Self::Error> {
+        serde_json::to_value(&value).and_then(serde_json::from_value).map_err(|_| value)
+    }
+}
+
+#[cfg(feature = "sdk")]
+impl TryFrom<InstructionError> for solana_sdk::instruction::InstructionError {
+    type Error = InstructionError;
+
+    fn try_from(value: InstructionError) -> std::result::Result<Self, Self::Error> {
+        serde_json::to_value(&value).and_then(serde_json::from_value).map_err(|_| value)
+    }
+}
+
+/// A Serum or Raydium action.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum DexTransaction {
+    Swap(DexSwap),
+    #[serde(alias = "addLiquidity")]
+    Deposit(DexLiquidity),
+    #[serde(alias = "removeLiquidity")]
+    Withdraw(DexLiquidity),
+    #[serde(alias = "placeOrder")]
+    NewOrder(DexOrder),
+    CancelOrder(DexOrder),
+    /// Any other action, or one that could not be parsed.
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+/// The version of a transaction message, serialized as `"legacy"` or a number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum TransactionVersion {
+    #[default]
+    #[serde(with = "legacy_version")]
+    Legacy,
+    Number(u8),
+}
+
+impl TransactionVersion {
+    /// The first versioned message format, which supports address lookup tables.
+    pub const V0: Self = Self::Number(0);
 }
 
 #[cfg(feature = "sdk-full")]
-impl From<Transaction> for solana_sdk::transaction::Transaction {
-    fn from(value: Transaction) -> Self {
-        let Transaction { message, signatures } = value;
-        let TransactionMessage {
+impl From<TransactionVersion> for solana_sdk::transaction::TransactionVersion {
+    fn from(value: TransactionVersion) -> Self {
+        match value {
+            TransactionVersion::Legacy => Self::LEGACY,
+            TransactionVersion::Number(version) => Self::Number(version),
+        }
+    }
+}
+
+mod legacy_version {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str("legacy")
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<(), D::Error> {
+        match <&str>::deserialize(d)? {
+            "legacy" => Ok(()),
+            s => Err(D::Error::custom(format!("invalid transaction version: {s}"))),
+        }
+    }
+}
+
+/// An instruction, either compiled against the message account keys or decoded by the API.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Instruction {
+    Compiled(CompiledInstruction),
+    Parsed(JsonParsedInstruction),
+    PartiallyDecoded(PartiallyDecodedInstruction),
+}
+
+impl Instruction {
+    /// Returns the invocation depth of the instruction, if known. Top-level instructions have a
+    /// stack height of `1`.
+    pub fn stack_height(&self) -> Option<u32> {
+        match self {
+            Self::Compiled(ix) => ix.stack_height,
+            Self::Parsed(ix) => ix.stack_height,
+            Self::PartiallyDecoded(ix) => ix.stack_height,
+        }
+    }
+
+    /// Returns the program ID, resolving compiled instructions against `account_keys`.
+    pub fn program_id<'a>(
+        &'a self,
+        account_keys: &'a [TransactionAccountKey],
+    ) -> Option<&'a Pubkey> {
+        match self {
+            Self::Compiled(ix) => {
+                account_keys.get(ix.program_id_index as usize).map(|key| &key.pubkey)
+            }
+            Self::Parsed(ix) => Some(&ix.program_id),
+            Self::PartiallyDecoded(ix) => Some(&ix.program_id),
+        }
+    }
+
+    /// Returns the accounts, resolving compiled instructions against `account_keys`.
+    ///
+    /// Returns `None` for parsed instructions, or if an account index is out of bounds.
+    pub fn accounts(&self, account_keys: &[TransactionAccountKey]) -> Option<Vec<Pubkey>> {
+        match self {
+            Self::Compiled(ix) => ix
+                .accounts
+                .iter()
+                .map(|&i| account_keys.get(i as usize).map(|key| key.pubkey.to_owned()))
+                .collect(),
+            Self::Parsed(_) => None,
+            Self::PartiallyDecoded(ix) => Some(ix.accounts.clone()),
+        }
+    }
+
+    /// Returns the base58-encoded instruction data. Returns `None` for parsed instructions.
+    pub fn data(&self) -> Option<&str> {
+        match self {
+            Self::Compiled(ix) => Some(&ix.data),
+            Self::Parsed(_) => None,
+            Self::PartiallyDecoded(ix) => Some(&ix.data),
+        }
+    }
+
+    /// Converts the instruction into a [`solana_sdk::instruction::Instruction`], resolving the
+    /// accounts and their signer and writable flags against `account_keys`.
+    ///
+    /// Returns `None` for parsed instructions, or if an account is missing or the data is not
+    /// valid base58.
+    #[cfg(feature = "sdk")]
+    pub fn to_instruction(
+        &self,
+        account_keys: &[TransactionAccountKey],
+    ) -> Option<solana_sdk::instruction::Instruction> {
+        use solana_sdk::instruction::AccountMeta;
+
+        let program_id = *self.program_id(account_keys)?;
+        let data = solana_sdk::bs58::decode(self.data()?).into_vec().ok()?;
+        let accounts = self
+            .accounts(account_keys)?
+            .into_iter()
+            .map(|pubkey| {
+                let key = account_keys.iter().find(|key| key.pubkey == pubkey)?;
+                Some(AccountMeta { pubkey, is_signer: key.signer, is_writable: key.writable })
+            })
+            .collect::<Option<_>>()?;
+        Some(solana_sdk::instruction::Instruction { program_id, accounts, data })
+    }
+}
+
+#[cfg(feature = "sdk")]
+impl TryFrom<CompiledInstruction> for solana_sdk::instruction::CompiledInstruction {
+    type Error = solana_sdk::bs58::decode::Error;
+
+    fn try_from(value: CompiledInstruction) -> std::result::Result<Self, Self::Error> {
+        let CompiledInstruction { program_id_index, accounts, data, stack_height: _ } = value;
+        let data = solana_sdk::bs58::decode(data).into_vec()?;
+        Ok(Self { program_id_index, accounts, data })
+    }
+}
+
+impl TransactionTokenBalance {
+    /// Returns the lossless amount before the transaction.
+    pub fn pre_amount(&self) -> std::result::Result<RawAmount, ParseAmountError> {
+        RawAmount::parse_raw(&self.amount.pre_amount, to_decimals(self.token.decimals)?)
+    }
+
+    /// Returns the lossless amount after the transaction.
+    pub fn post_amount(&self) -> std::result::Result<RawAmount, ParseAmountError> {
+        RawAmount::parse_raw(&self.amount.post_amount, to_decimals(self.token.decimals)?)
+    }
+}
+
+impl DexTokenAmount {
+    /// Returns the lossless amount.
+    pub fn raw_amount(&self) -> std::result::Result<RawAmount, ParseAmountError> {
+        RawAmount::parse_raw(&self.amount, to_decimals(self.decimals)?)
+    }
+}
+
+impl TokenTransfer {
+    /// Returns the lossless transferred amount.
+    pub fn raw_amount(&self) -> std::result::Result<RawAmount, ParseAmountError> {
+        RawAmount::parse_raw(&self.amount, to_decimals(self.token.decimals)?)
+    }
+
+    /// Returns the mint address of the transferred token.
+    pub fn mint(&self) -> &Pubkey {
+        &self.token.address
+    }
+
+    /// Returns the decimals of the transferred token.
+    pub fn decimals(&self) -> u64 {
+        self.token.decimals
+    }
+
+    /// Returns the symbol of the transferred token, if known.
+    pub fn symbol(&self) -> Option<&str> {
+        self.token.symbol.as_deref()
+    }
+}
+
+impl JsonParsedInstruction {
+    /// Returns the typed instruction.
+    pub fn kind(&self) -> ParsedInstructionKind {
+        ParsedInstructionKind::parse(&self.program_id, &self.parsed)
+    }
+}
+
+impl TransactionParsedInstruction {
+    /// Returns the typed instruction.
+    pub fn kind(&self) -> ParsedInstructionKind {
+        match &self.r#type {
+            Some(ty) => {
+                let parsed = serde_json::json!({ "type": ty, "info": self.params });
+                ParsedInstructionKind::parse(&self.program_id, &parsed)
+            }
+            None => ParsedInstructionKind::Unknown(self.params.clone()),
+        }
+    }
+}
+
+impl TransactionMeta {
+    /// Returns the instructions invoked by the top-level instruction at `index`.
+    pub fn inner_instructions_at(&self, index: u8) -> &[Instruction] {
+        self.inner_instructions
+            .iter()
+            .find(|inner| inner.index == index)
+            .map_or(&[], |inner| &inner.instructions[..])
+    }
+}
+
+/// The maximum number of addresses accepted by the `/account/metadata/multi` path.
+const METADATA_MULTI_LIMIT: usize = 50;
+
+/// A [`GetTransactionInfo`] along with the metadata of the accounts it references.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LabeledTransaction {
+    pub transaction: GetTransactionInfo,
+    /// The metadata of the accounts referenced by the transaction, keyed by address. See
+    /// [`GetTransactionInfo::pubkeys`].
+    pub accounts: HashMap<Pubkey, AccountMetadata>,
+}
+
+impl LabeledTransaction {
+    /// Returns the metadata of `pubkey`, if it is referenced by the transaction.
+    pub fn metadata(&self, pubkey: &Pubkey) -> Option<&AccountMetadata> {
+        self.accounts.get(pubkey)
+    }
+
+    /// Returns the Solscan label of `pubkey`, if it has one.
+    pub fn label(&self, pubkey: &Pubkey) -> Option<&str> {
+        self.metadata(pubkey)?.account_label.as_deref()
+    }
+}
+
+impl GetTransactionInfo {
+    /// Returns the unique addresses referenced by the transaction, in order of appearance: the
+    /// signers, input accounts, token and SOL transfer accounts and owners, parsed instruction
+    /// program IDs, and DEX pools, markets, users and tokens.
+    pub fn pubkeys(&self) -> Vec<Pubkey> {
+        let accounts = self.input_accounts.iter().map(|account| &account.account);
+        let token_transfers = self.token_transfers.iter().flat_map(|transfer| {
+            [&transfer.source, &transfer.destination]
+                .into_iter()
+                .chain(&transfer.source_owner)
+                .chain(&transfer.destination_owner)
+        });
+        let sol_transfers = self
+            .sol_transfers
+            .iter()
+            .flat_map(|transfer| [&transfer.source, &transfer.destination]);
+        let programs = self.parsed_instructions.iter().map(|ix| &ix.program_id);
+        let dex = self.serum_transactions.iter().chain(&self.raydium_transactions);
+
+        let mut pubkeys: Vec<Pubkey> = Vec::with_capacity(self.input_accounts.len());
+        let all = self
+            .signers
+            .iter()
+            .chain(accounts)
+            .chain(token_transfers)
+            .chain(sol_transfers)
+            .chain(programs)
+            .chain(dex.flat_map(DexTransaction::pubkeys));
+        for pubkey in all {
+            if !pubkeys.contains(pubkey) {
+                pubkeys.push(pubkey.to_owned());
+            }
+        }
+        pubkeys
+    }
+
+    /// Splits [`Self::pubkeys`] into batches accepted by the `/account/metadata/multi` path.
+    fn metadata_batches(&self) -> Vec<Vec<Pubkey>> {
+        self.pubkeys().chunks(METADATA_MULTI_LIMIT).map(<[Pubkey]>::to_vec).collect()
+    }
+}
+
+impl DexTransaction {
+    /// Returns the pool or market, user and token addresses of the action.
+    fn pubkeys(&self) -> Vec<&Pubkey> {
+        match self {
+            Self::Swap(swap) => vec![
+                &swap.pool,
+                &swap.user,
+                &swap.token_in.token_address,
+                &swap.token_out.token_address,
+            ],
+            Self::Deposit(liquidity) | Self::Withdraw(liquidity) => {
+                let tokens = [&liquidity.token_a, &liquidity.token_b];
+                [&liquidity.pool, &liquidity.user]
+                    .into_iter()
+                    .chain(tokens.into_iter().chain(&liquidity.lp_token).map(|t| &t.token_address))
+                    .collect()
+            }
+            Self::NewOrder(order) | Self::CancelOrder(order) => [&order.market, &order.user]
+                .into_iter()
+                .chain(order.size.as_ref().map(|size| &size.token_address))
+                .collect(),
+            Self::Unknown(_) => Vec::new(),
+        }
+    }
+}
+
+/// An error that occurred while converting a [`Transaction`] into a Solana SDK transaction.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum TransactionConversionError {
+    #[error("account keys are not ordered by their signer and writable flags")]
+    UnorderedAccountKeys,
+
+    #[error("too many account keys")]
+    TooManyAccountKeys,
+
+    #[error("instruction {0} was parsed by the API and has no raw data")]
+    ParsedInstruction(usize),
+
+    #[error("instruction {0} references an account that is not in the account keys")]
+    MissingAccount(usize),
+
+    #[error("instruction {0} data is not valid base58")]
+    InvalidData(usize),
+
+    #[error("address table lookups can only be converted into a versioned transaction")]
+    AddressTableLookups,
+
+    #[error("unsupported transaction version {0}")]
+    UnsupportedVersion(u8),
+
+    #[error(
+        "account key {0} is read-only but precedes a writable key, so it may have been demoted"
+    )]
+    DemotedAccountKey(usize),
+
+    #[error("expected {expected} signatures, found {found}")]
+    SignatureCount { expected: u8, found: usize },
+
+    #[error("the signatures do not verify against the converted message")]
+    InvalidSignatures,
+
+    #[error("invalid transaction status meta: {0}")]
+    InvalidMeta(String),
+}
+
+impl TransactionMessage {
+    /// Returns the account keys that are stored in the message, as opposed to loaded from
+    /// address lookup tables.
+    pub fn static_account_keys(&self) -> impl Iterator<Item = &TransactionAccountKey> {
+        self.account_keys.iter().filter(|key| key.source != "lookupTable")
+    }
+
+    /// Computes the message header from the signer and writable flags of the static account
+    /// keys.
+    ///
+    /// The RPC reports program IDs, sysvars and builtin programs as read-only even when the
+    /// message lists them as writable, so the header cannot always be recovered:
+    ///
+    /// - A read-only key that precedes a writable key of the same kind must have been demoted. The
+    ///   header is ambiguous, and [`TransactionConversionError::DemotedAccountKey`] is returned.
+    /// - A demoted key that was the last writable key of its kind looks like a read-only key, and
+    ///   the header is off by one. Converting into a Solana SDK transaction catches this by
+    ///   verifying the signatures.
+    ///
+    /// Also returns an error if a signer follows a non-signer.
+    pub fn header(&self) -> std::result::Result<MessageHeader, TransactionConversionError> {
+        use TransactionConversionError::*;
+
+        let keys: Vec<_> = self.static_account_keys().collect();
+        let num_signers = keys.iter().take_while(|key| key.signer).count();
+        let (signed, unsigned) = keys.split_at(num_signers);
+        if unsigned.iter().any(|key| key.signer) {
+            return Err(UnorderedAccountKeys);
+        }
+        let num_readonly = |offset: usize, keys: &[&TransactionAccountKey]| {
+            let num_writable = keys.iter().take_while(|key| key.writable).count();
+            if keys[num_writable..].iter().any(|key| key.writable) {
+                return Err(DemotedAccountKey(offset + num_writable));
+            }
+            u8::try_from(keys.len() - num_writable).map_err(|_| TooManyAccountKeys)
+        };
+        Ok(MessageHeader {
+            num_required_signatures: u8::try_from(num_signers).map_err(|_| TooManyAccountKeys)?,
+            num_readonly_signed_accounts: num_readonly(0, signed)?,
+            num_readonly_unsigned_accounts: num_readonly(num_signers, unsigned)?,
+        })
+    }
+
+    /// Compiles the top-level instructions against all of the account keys.
+    #[cfg(feature = "sdk")]
+    fn compile_instructions(
+        &self,
+    ) -> std::result::Result<
+        Vec<solana_sdk::instruction::CompiledInstruction>,
+        TransactionConversionError,
+    > {
+        use TransactionConversionError::*;
+
+        let index_of = |i: usize, pubkey: &Pubkey| {
+            self.account_keys
+                .iter()
+                .position(|key| key.pubkey == *pubkey)
+                .and_then(|index| u8::try_from(index).ok())
+                .ok_or(MissingAccount(i))
+        };
+        self.instructions
+            .iter()
+            .enumerate()
+            .map(|(i, ix)| {
+                let (program_id_index, accounts, data) = match ix {
+                    Instruction::Compiled(ix) => {
+                        let len = self.account_keys.len();
+                        let indexes = ix.accounts.iter().chain([&ix.program_id_index]);
+                        if indexes.into_iter().any(|&index| index as usize >= len) {
+                            return Err(MissingAccount(i));
+                        }
+                        (ix.program_id_index, ix.accounts.clone(), &ix.data)
+                    }
+                    Instruction::PartiallyDecoded(ix) => {
+                        let accounts = ix
+                            .accounts
+                            .iter()
+                            .map(|pubkey| index_of(i, pubkey))
+                            .collect::<std::result::Result<_, _>>()?;
+                        (index_of(i, &ix.program_id)?, accounts, &ix.data)
+                    }
+                    Instruction::Parsed(_) => return Err(ParsedInstruction(i)),
+                };
+                let data = solana_sdk::bs58::decode(data).into_vec().map_err(|_| InvalidData(i))?;
+                Ok(solana_sdk::instruction::CompiledInstruction {
+                    program_id_index,
+                    accounts,
+                    data,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Fails if the message loads accounts from address lookup tables, or if any instruction was
+/// parsed by the API, as the raw instruction data is then not available.
+#[cfg(feature = "sdk")]
+impl TryFrom<TransactionMessage> for solana_sdk::message::Message {
+    type Error = TransactionConversionError;
+
+    fn try_from(value: TransactionMessage) -> std::result::Result<Self, Self::Error> {
+        let has_lookups = value.address_table_lookups.as_ref().map_or(false, |l| !l.is_empty());
+        if has_lookups || value.static_account_keys().count() != value.account_keys.len() {
+            return Err(TransactionConversionError::AddressTableLookups);
+        }
+        let header = value.header()?.into();
+        let instructions = value.compile_instructions()?;
+        Ok(Self {
+            header,
+            account_keys: value.account_keys.into_iter().map(|key| key.pubkey).collect(),
+            recent_blockhash: value.recent_blockhash,
+            instructions,
+        })
+    }
+}
+
+/// Fails if any instruction was parsed by the API, as the raw instruction data is then not
+/// available.
+#[cfg(feature = "sdk")]
+impl TryFrom<TransactionMessage> for solana_sdk::message::v0::Message {
+    type Error = TransactionConversionError;
+
+    fn try_from(value: TransactionMessage) -> std::result::Result<Self, Self::Error> {
+        let header = value.header()?.into();
+        let instructions = value.compile_instructions()?;
+        let account_keys = value.static_account_keys().map(|key| key.pubkey).collect();
+        Ok(Self {
+            header,
             account_keys,
-            address_table_lookups: _,
-            instructions: _,
-            recent_blockhash,
-        } = message;
+            recent_blockhash: value.recent_blockhash,
+            instructions,
+            address_table_lookups: value
+                .address_table_lookups
+                .unwrap_or_default()
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+}
 
-        let account_keys = account_keys.into_iter().map(|key| key.pubkey).collect();
-        let message =
-            solana_sdk::message::Message { account_keys, recent_blockhash, ..Default::default() };
+#[cfg(feature = "sdk")]
+impl From<MessageHeader> for solana_sdk::message::MessageHeader {
+    fn from(value: MessageHeader) -> Self {
+        let MessageHeader {
+            num_required_signatures,
+            num_readonly_signed_accounts,
+            num_readonly_unsigned_accounts,
+        } = value;
+        Self {
+            num_required_signatures,
+            num_readonly_signed_accounts,
+            num_readonly_unsigned_accounts,
+        }
+    }
+}
 
-        Self { signatures, message }
+#[cfg(feature = "sdk")]
+impl From<AddressTableLookup> for solana_sdk::message::v0::MessageAddressTableLookup {
+    fn from(value: AddressTableLookup) -> Self {
+        let AddressTableLookup { account_key, writable_indexes, readonly_indexes } = value;
+        Self { account_key, writable_indexes, readonly_indexes }
+    }
+}
+
+#[cfg(feature = "sdk-full")]
+impl TryFrom<TransactionInfo> for solana_sdk::transaction::Transaction {
+    type Error = TransactionConversionError;
+
+    fn try_from(value: TransactionInfo) -> std::result::Result<Self, Self::Error> {
+        value.transaction.try_into()
+    }
+}
+
+/// Builds a `v0` message if the version is `0`, and a legacy message otherwise. Fails if the
+/// number of signatures does not match the header, or if they do not verify against the converted
+/// message.
+#[cfg(feature = "sdk-full")]
+impl TryFrom<TransactionInfo> for solana_sdk::transaction::VersionedTransaction {
+    type Error = TransactionConversionError;
+
+    fn try_from(value: TransactionInfo) -> std::result::Result<Self, Self::Error> {
+        use solana_sdk::message::VersionedMessage;
+
+        let Transaction { message, signatures } = value.transaction;
+        let message = match value.version.unwrap_or_default() {
+            TransactionVersion::Legacy => VersionedMessage::Legacy(message.try_into()?),
+            TransactionVersion::V0 => VersionedMessage::V0(message.try_into()?),
+            TransactionVersion::Number(version) => {
+                return Err(TransactionConversionError::UnsupportedVersion(version));
+            }
+        };
+        let expected = message.header().num_required_signatures;
+        if signatures.len() != expected as usize {
+            let found = signatures.len();
+            return Err(TransactionConversionError::SignatureCount { expected, found });
+        }
+        let signatures = signatures.into_iter().map(Into::into).collect();
+        let transaction = Self { signatures, message };
+        if !transaction.verify_with_results().into_iter().all(|ok| ok) {
+            return Err(TransactionConversionError::InvalidSignatures);
+        }
+        Ok(transaction)
+    }
+}
+
+/// Fails if the number of signatures does not match the header, or if they do not verify against
+/// the converted message, e.g. because a demoted writable key made the header off by one. See
+/// [`TransactionMessage::header`].
+#[cfg(feature = "sdk-full")]
+impl TryFrom<Transaction> for solana_sdk::transaction::Transaction {
+    type Error = TransactionConversionError;
+
+    fn try_from(value: Transaction) -> std::result::Result<Self, Self::Error> {
+        let Transaction { message, signatures } = value;
+        let message: solana_sdk::message::Message = message.try_into()?;
+        let expected = message.header.num_required_signatures;
+        if signatures.len() != expected as usize {
+            let found = signatures.len();
+            return Err(TransactionConversionError::SignatureCount { expected, found });
+        }
+        let signatures = signatures.into_iter().map(Into::into).collect();
+        let transaction = Self { signatures, message };
+        transaction.verify().map_err(|_| TransactionConversionError::InvalidSignatures)?;
+        Ok(transaction)
     }
 }
 
@@ -129,10 +1060,452 @@ impl Client {
     pub async fn transaction(&self, signature: &Signature) -> Result<GetTransactionInfo> {
         self.get_no_query(&concat_1("transaction/", &signature.to_string())).await
     }
+
+    /// Fetches the metadata of every account referenced by `transaction` with the Pro API
+    /// `/account/metadata/multi` path.
+    pub async fn label_transaction(
+        &self,
+        transaction: GetTransactionInfo,
+    ) -> Result<LabeledTransaction> {
+        let mut accounts = HashMap::new();
+        for pubkeys in transaction.metadata_batches() {
+            for metadata in self.account_metadata_multi(&pubkeys).await? {
+                accounts.insert(metadata.account_address.to_owned(), metadata);
+            }
+        }
+        Ok(LabeledTransaction { transaction, accounts })
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transaction_meta() {
+        let meta: TransactionMeta = serde_json::from_value(serde_json::json!({
+            "err": null,
+            "fee": 5000,
+            "innerInstructions": [{
+                "index": 1,
+                "instructions": [
+                    { "programIdIndex": 2, "accounts": [0, 1], "data": "3Bxs4h24hBtQy9rw", "stackHeight": 2 },
+                    {
+                        "program": "spl-token",
+                        "programId": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                        "parsed": { "type": "transfer", "info": { "amount": "1" } },
+                        "stackHeight": 2
+                    },
+                    {
+                        "programId": "11111111111111111111111111111111",
+                        "accounts": ["TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"],
+                        "data": "3Bxs4h24hBtQy9rw"
+                    }
+                ]
+            }],
+            "logMessages": [],
+            "postBalances": [],
+            "postTokenBalances": [{
+                "accountIndex": 1,
+                "mint": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+                "owner": "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn",
+                "programId": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                "uiTokenAmount": {
+                    "amount": "0",
+                    "decimals": 6,
+                    "uiAmount": null,
+                    "uiAmountString": "0"
+                }
+            }],
+            "preBalances": [],
+            "preTokenBalances": [],
+            "rewards": null,
+            "status": null
+        }))
+        .unwrap();
+
+        let ixs = meta.inner_instructions_at(1);
+        assert!(meta.inner_instructions_at(0).is_empty());
+        assert!(matches!(
+            ixs[0],
+            Instruction::Compiled(CompiledInstruction { program_id_index: 2, .. })
+        ));
+        let Instruction::Parsed(parsed) = &ixs[1] else { panic!("{:?}", ixs[1]) };
+        assert!(matches!(parsed.kind(), ParsedInstructionKind::Unknown(_)));
+        assert!(matches!(ixs[2], Instruction::PartiallyDecoded(_)));
+        assert_eq!(ixs[0].stack_height(), Some(2));
+        assert_eq!(ixs[2].data(), Some("3Bxs4h24hBtQy9rw"));
+
+        let balance = &meta.post_token_balances[0];
+        assert_eq!(balance.account_index, 1);
+        assert_eq!(balance.ui_token_amount.decimals, 6);
+        assert_eq!(balance.ui_token_amount.ui_amount, None);
+    }
+
+    #[test]
+    fn test_transfers() {
+        let token_transfer: TokenTransfer = serde_json::from_value(serde_json::json!({
+            "source": "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn",
+            "destination": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+            "source_owner": "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn",
+            "destination_owner": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+            "amount": "1500000",
+            "token": {
+                "address": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+                "decimals": 6,
+                "symbol": "RAY"
+            },
+            "type": "transfer"
+        }))
+        .unwrap();
+        assert_eq!(token_transfer.amount, "1500000");
+        assert_eq!(token_transfer.raw_amount().unwrap().to_string(), "1.5");
+        assert_eq!(token_transfer.decimals(), 6);
+        assert_eq!(token_transfer.symbol(), Some("RAY"));
+        assert!(token_transfer.source_owner.is_some());
+
+        let sol_transfer: SolTransfer = serde_json::from_value(serde_json::json!({
+            "source": "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn",
+            "destination": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+            "amount": 5000
+        }))
+        .unwrap();
+        assert_eq!(sol_transfer.lamports, 5000);
+    }
+
+    #[test]
+    fn test_dex_transactions() {
+        let txs: Vec<DexTransaction> = serde_json::from_value(serde_json::json!([
+            {
+                "type": "swap",
+                "ammId": "58oQChx4yWmvKdwLLZzBi4ChoCc2fqCUWBkwMihLYQo2",
+                "owner": "3SKLz31aEBqQQYeiGaezGP7v7ZEJvAmSGwBqU1zLJkgn",
+                "tokenIn": {
+                    "tokenAddress": "So11111111111111111111111111111111111111112",
+                    "amount": "1000000000",
+                    "decimals": 9
+                },
+                "tokenOut": {
+                    "tokenAddress": "4k3Dyjzvzp8eMZWUXbBCjEvwSkkk59S5iCNLY3QrkX6R",
+                    "amount": "12345678",
+                    "decimals": 6,
+                    "symbol": "RAY"
+                }
+            },
+            { "type": "somethingNew", "foo": 1 },
+            "not an object"
+        ]))
+        .unwrap();
+        let DexTransaction::Swap(swap) = &txs[0] else { panic!("{:?}", txs[0]) };
+        assert_eq!(swap.token_in.decimals, 9);
+        assert_eq!(swap.token_out.symbol.as_deref(), Some("RAY"));
+        assert!(matches!(txs[1], DexTransaction::Unknown(_)));
+        assert!(matches!(txs[2], DexTransaction::Unknown(_)));
+    }
+
+    #[test]
+    fn test_transaction_pubkeys() {
+        let key = |i: u8| Pubkey::from([i; 32]);
+        let token = |i| DexTokenAmount { token_address: key(i), ..Default::default() };
+        let mut info = GetTransactionInfo {
+            signers: vec![key(0)],
+            input_accounts: vec![
+                TransactionInputAccount { account: key(0), ..Default::default() },
+                TransactionInputAccount { account: key(1), ..Default::default() },
+            ],
+            token_transfers: vec![TokenTransfer {
+                source: key(2),
+                destination: key(3),
+                source_owner: Some(key(0)),
+                destination_owner: Some(key(4)),
+                ..Default::default()
+            }],
+            sol_transfers: vec![SolTransfer { source: key(0), destination: key(5), lamports: 1 }],
+            parsed_instructions: vec![TransactionParsedInstruction {
+                program_id: key(6),
+                ..Default::default()
+            }],
+            raydium_transactions: vec![
+                DexTransaction::Swap(DexSwap {
+                    pool: key(7),
+                    user: key(0),
+                    token_in: token(8),
+                    token_out: token(9),
+                }),
+                DexTransaction::Unknown(Value::Null),
+            ],
+            ..Default::default()
+        };
+        let expected: Vec<_> = (0..10).map(key).collect();
+        assert_eq!(info.pubkeys(), expected);
+        assert_eq!(info.metadata_batches(), [expected]);
+
+        let accounts =
+            (10..=100).map(|i| TransactionInputAccount { account: key(i), ..Default::default() });
+        info.input_accounts.extend(accounts);
+        let batches = info.metadata_batches();
+        assert_eq!(batches.iter().map(Vec::len).collect::<Vec<_>>(), [50, 50, 1]);
+        assert_eq!(batches.concat(), info.pubkeys());
+    }
+
+    #[test]
+    fn test_transaction_error() {
+        let err: TransactionError = serde_json::from_value(
+            serde_json::json!({ "InstructionError": [2, { "Custom": 6001 }] }),
+        )
+        .unwrap();
+        assert_eq!(err, TransactionError::InstructionError(2, InstructionError::Custom(6001)));
+        assert_eq!(err.custom_error(), Some((2, 6001)));
+
+        let err: TransactionError =
+            serde_json::from_value(serde_json::json!("AccountInUse")).unwrap();
+        assert_eq!(err, TransactionError::AccountInUse);
+
+        let err: TransactionError = serde_json::from_value(
+            serde_json::json!({ "InsufficientFundsForRent": { "account_index": 1 } }),
+        )
+        .unwrap();
+        assert_eq!(err, TransactionError::InsufficientFundsForRent { account_index: 1 });
+
+        let err: TransactionError =
+            serde_json::from_value(serde_json::json!("SomethingNew")).unwrap();
+        assert_eq!(err, TransactionError::Unknown("SomethingNew".into()));
+
+        let status: Option<std::result::Result<(), TransactionError>> =
+            serde_json::from_value(serde_json::json!({ "Err": "BlockhashNotFound" })).unwrap();
+        assert_eq!(status, Some(Err(TransactionError::BlockhashNotFound)));
+
+        let status: TxStatus = serde_json::from_value(serde_json::json!("Fail")).unwrap();
+        assert!(status.is_fail());
+    }
+
+    #[cfg(feature = "sdk")]
+    #[test]
+    fn test_transaction_error_sdk() {
+        use solana_sdk::{
+            instruction::InstructionError as SdkIxError, transaction::TransactionError as SdkError,
+        };
+
+        let err = TransactionError::InstructionError(2, InstructionError::Custom(6001));
+        assert_eq!(
+            SdkError::try_from(err).unwrap(),
+            SdkError::InstructionError(2, SdkIxError::Custom(6001))
+        );
+
+        let err = TransactionError::Unknown("SomethingNew".into());
+        assert_eq!(SdkError::try_from(err.clone()).unwrap_err(), err);
+    }
+
+    #[test]
+    fn test_message_header() {
+        let key = |signer, writable| TransactionAccountKey {
+            signer,
+            writable,
+            source: "transaction".to_string(),
+            ..Default::default()
+        };
+        let mut message = TransactionMessage {
+            account_keys: vec![
+                key(true, true),
+                key(true, false),
+                key(false, true),
+                key(false, false),
+                key(false, false),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            message.header(),
+            Ok(MessageHeader {
+                num_required_signatures: 2,
+                num_readonly_signed_accounts: 1,
+                num_readonly_unsigned_accounts: 2,
+            })
+        );
+
+        // A writable program ID, reported as read-only.
+        message.account_keys.insert(2, key(false, false));
+        assert_eq!(message.header(), Err(TransactionConversionError::DemotedAccountKey(2)));
+
+        message.account_keys.remove(2);
+        message.account_keys.push(key(true, true));
+        assert_eq!(message.header(), Err(TransactionConversionError::UnorderedAccountKeys));
+    }
+
+    #[cfg(feature = "sdk-full")]
+    #[test]
+    fn test_transaction_into_sdk() {
+        use solana_sdk::{
+            hash::Hash, message::VersionedMessage, signature::Keypair, signer::Signer,
+            system_instruction, transaction::Transaction as SdkTransaction,
+        };
+
+        let payer = Keypair::new();
+        let to = Pubkey::new_unique();
+        let ixs =
+            [system_instruction::transfer(&payer.pubkey(), &to, 1_000), spl_memo(&payer.pubkey())];
+        let expected = SdkTransaction::new_signed_with_payer(
+            &ixs,
+            Some(&payer.pubkey()),
+            &[&payer],
+            Hash::new_unique(),
+        );
+
+        let message = VersionedMessage::Legacy(expected.message.clone());
+        let mut transaction = Transaction {
+            message: api_message(&message, &[]),
+            signatures: expected.signatures.iter().map(|&sig| sig.into()).collect(),
+        };
+        let memo = &expected.message.instructions[1];
+        transaction.message.instructions[1] =
+            Instruction::PartiallyDecoded(PartiallyDecodedInstruction {
+                program_id: expected.message.account_keys[memo.program_id_index as usize],
+                accounts: vec![payer.pubkey()],
+                data: solana_sdk::bs58::encode(&memo.data).into_string(),
+                stack_height: None,
+            });
+        let account_keys = transaction.message.account_keys.clone();
+        let converted = SdkTransaction::try_from(transaction.clone()).unwrap();
+        assert_eq!(converted, expected);
+        converted.verify().unwrap();
+
+        transaction.message.account_keys.swap(0, 1);
+        assert_eq!(
+            SdkTransaction::try_from(transaction.clone()),
+            Err(TransactionConversionError::UnorderedAccountKeys)
+        );
+        transaction.message.account_keys = account_keys.clone();
+        transaction.message.account_keys[1].writable = false;
+        assert_eq!(
+            SdkTransaction::try_from(transaction.clone()),
+            Err(TransactionConversionError::InvalidSignatures)
+        );
+        transaction.message.account_keys = account_keys;
+        let signatures = std::mem::take(&mut transaction.signatures);
+        assert_eq!(
+            SdkTransaction::try_from(transaction.clone()),
+            Err(TransactionConversionError::SignatureCount { expected: 1, found: 0 })
+        );
+        transaction.signatures = signatures;
+        transaction.message.instructions[0] = Instruction::Parsed(Default::default());
+        assert_eq!(
+            SdkTransaction::try_from(transaction),
+            Err(TransactionConversionError::ParsedInstruction(0))
+        );
+    }
+
+    #[cfg(feature = "sdk-full")]
+    #[test]
+    fn test_versioned_transaction_into_sdk() {
+        use solana_sdk::{
+            address_lookup_table_account::AddressLookupTableAccount,
+            hash::Hash,
+            message::{v0, VersionedMessage},
+            signature::Keypair,
+            signer::Signer,
+            system_instruction,
+            transaction::VersionedTransaction,
+        };
+
+        let payer = Keypair::new();
+        let to = Pubkey::new_unique();
+        let table = AddressLookupTableAccount { key: Pubkey::new_unique(), addresses: vec![to] };
+        let ixs = [system_instruction::transfer(&payer.pubkey(), &to, 1_000)];
+        let message =
+            v0::Message::try_compile(&payer.pubkey(), &ixs, &[table], Hash::new_unique()).unwrap();
+        let expected =
+            VersionedTransaction::try_new(VersionedMessage::V0(message.clone()), &[&payer])
+                .unwrap();
+
+        let mut info = TransactionInfo {
+            meta: None,
+            transaction: Transaction {
+                message: api_message(&VersionedMessage::V0(message), &[to]),
+                signatures: expected.signatures.iter().map(|&sig| sig.into()).collect(),
+            },
+            version: serde_json::from_str("0").unwrap(),
+        };
+        assert_eq!(VersionedTransaction::try_from(info.clone()), Ok(expected));
+
+        info.version = serde_json::from_str(r#""legacy""#).unwrap();
+        assert_eq!(
+            VersionedTransaction::try_from(info.clone()),
+            Err(TransactionConversionError::AddressTableLookups)
+        );
+
+        info.version = serde_json::from_str("0").unwrap();
+        info.transaction.message.recent_blockhash = Hash::default();
+        assert_eq!(
+            VersionedTransaction::try_from(info.clone()),
+            Err(TransactionConversionError::InvalidSignatures)
+        );
+
+        info.transaction.signatures.clear();
+        assert_eq!(
+            VersionedTransaction::try_from(info),
+            Err(TransactionConversionError::SignatureCount { expected: 1, found: 0 })
+        );
+    }
+
+    /// Mirrors `message` as returned by the API, with `loaded` being the addresses loaded from
+    /// address lookup tables.
+    #[cfg(feature = "sdk-full")]
+    fn api_message(
+        message: &solana_sdk::message::VersionedMessage,
+        loaded: &[Pubkey],
+    ) -> TransactionMessage {
+        let num_signers = message.header().num_required_signatures as usize;
+        let static_keys = message.static_account_keys().iter().map(|key| (key, "transaction"));
+        let loaded_keys = loaded.iter().map(|key| (key, "lookupTable"));
+        let account_keys = static_keys
+            .chain(loaded_keys)
+            .enumerate()
+            .map(|(i, (&pubkey, source))| TransactionAccountKey {
+                pubkey,
+                signer: i < num_signers,
+                source: source.to_string(),
+                writable: message.is_maybe_writable(i),
+            })
+            .collect();
+        let instructions = message
+            .instructions()
+            .iter()
+            .map(|ix| {
+                Instruction::Compiled(CompiledInstruction {
+                    program_id_index: ix.program_id_index,
+                    accounts: ix.accounts.clone(),
+                    data: solana_sdk::bs58::encode(&ix.data).into_string(),
+                    stack_height: None,
+                })
+            })
+            .collect();
+        let address_table_lookups = message.address_table_lookups().map(|lookups| {
+            lookups
+                .iter()
+                .map(|lookup| AddressTableLookup {
+                    account_key: lookup.account_key,
+                    writable_indexes: lookup.writable_indexes.clone(),
+                    readonly_indexes: lookup.readonly_indexes.clone(),
+                })
+                .collect()
+        });
+        TransactionMessage {
+            account_keys,
+            address_table_lookups,
+            instructions,
+            recent_blockhash: *message.recent_blockhash(),
+        }
+    }
+
+    #[cfg(feature = "sdk-full")]
+    fn spl_memo(signer: &Pubkey) -> solana_sdk::instruction::Instruction {
+        solana_sdk::instruction::Instruction {
+            program_id: crate::program_ids::MEMO.parse().unwrap(),
+            accounts: vec![solana_sdk::instruction::AccountMeta::new_readonly(*signer, true)],
+            data: b"hello".to_vec(),
+        }
+    }
+
     test_route!(test_transaction_last: |c| c.transaction_last(Some(20)) => |res| {
         if !res.is_empty() {
             assert_eq!(res.len(), 20)
@@ -1,29 +1,37 @@
 use serde::{Deserialize, Deserializer, Serializer};
+use std::{fmt::Display, str::FromStr};
 
-pub fn serialize<S: Serializer>(value: &String, s: S) -> Result<S::Ok, S::Error> {
-    s.serialize_str(value)
+pub fn serialize<T: ToString, S: Serializer>(value: T, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_str(&value.to_string())
 }
 
-pub fn deserialize<'de, D>(d: D) -> Result<String, D::Error>
+pub fn deserialize<'de, E, T, D>(d: D) -> Result<T, D::Error>
 where
+    T: FromStr<Err = E>,
+    E: Display,
     D: Deserializer<'de>,
 {
-    String::deserialize(d)
+    String::deserialize(d).and_then(|s| T::from_str(&s).map_err(serde::de::Error::custom))
 }
 
 pub mod option {
     use super::*;
 
-    pub fn serialize<S: Serializer>(value: &Option<String>, s: S) -> Result<S::Ok, S::Error> {
+    pub fn serialize<T: ToString, S: Serializer>(
+        value: &Option<T>,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
         if let Some(value) = value {
-            s.serialize_some(&value)
+            s.serialize_some(&value.to_string())
         } else {
             s.serialize_none()
         }
     }
 
-    pub fn deserialize<'de, D>(d: D) -> Result<Option<String>, D::Error>
+    pub fn deserialize<'de, E, T, D>(d: D) -> Result<Option<T>, D::Error>
     where
+        T: FromStr<Err = E>,
+        E: Display,
         D: Deserializer<'de>,
     {
         match <Option<String>>::deserialize(d) {
@@ -31,7 +39,10 @@ pub mod option {
                 if s.is_empty() {
                     Ok(None)
                 } else {
-                    Ok(Some(s))
+                    match T::from_str(&s) {
+                        Ok(x) => Ok(Some(x)),
+                        Err(e) => Err(serde::de::Error::custom(e)),
+                    }
                 }
             }
             Ok(None) => Ok(None),
@@ -44,18 +55,25 @@ pub mod vec {
     use super::*;
     use serde::ser::SerializeSeq;
 
-    pub fn serialize<S: Serializer>(value: &Vec<String>, s: S) -> Result<S::Ok, S::Error> {
+    pub fn serialize<T: ToString, S: Serializer>(value: &Vec<T>, s: S) -> Result<S::Ok, S::Error> {
         let mut seq = s.serialize_seq(Some(value.len()))?;
         for value in value {
-            seq.serialize_element(&value)?;
+            seq.serialize_element(&value.to_string())?;
         }
         seq.end()
     }
 
-    pub fn deserialize<'de, D>(d: D) -> Result<Vec<String>, D::Error>
+    pub fn deserialize<'de, E, T, D>(d: D) -> Result<Vec<T>, D::Error>
     where
+        T: FromStr<Err = E>,
+        E: Display,
         D: Deserializer<'de>,
     {
-        <Vec<String>>::deserialize(d)
+        <Vec<String>>::deserialize(d).and_then(|v| {
+            v.into_iter()
+                .map(|s| T::from_str(&s))
+                .collect::<Result<Vec<T>, _>>()
+                .map_err(serde::de::Error::custom)
+        })
     }
 }
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserializer, Serializer};
+
+pub fn serialize<S: Serializer>(value: &DateTime<Utc>, s: S) -> Result<S::Ok, S::Error> {
+    chrono::serde::ts_seconds::serialize(value, s)
+}
+
+pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    chrono::serde::ts_seconds::deserialize(d)
+}
+
+pub mod option {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<DateTime<Utc>>,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        chrono::serde::ts_seconds_option::serialize(value, s)
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Option<DateTime<Utc>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        chrono::serde::ts_seconds_option::deserialize(d)
+    }
+}
@@ -1,21 +1,203 @@
+//! Solana types: [`Pubkey`], [`struct@Hash`] and [`Account`] are re-exported from `solana_sdk` with
+//! the `sdk` feature, or lightweight base58 newtypes otherwise. [`Signature`] is always the crate's
+//! own type.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// An error that occurred while parsing a base58-encoded byte array, such as a [`Signature`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum ParseBase58Error {
+    #[error("invalid base58 string")]
+    Invalid,
+
+    #[error("expected {expected} bytes, got {actual}")]
+    WrongSize { expected: usize, actual: usize },
+}
+
+macro_rules! base58_bytes {
+    ($(#[$attr:meta])* $name:ident($len:literal)) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name(pub [u8; $len]);
+
+        impl $name {
+            /// The length in bytes.
+            pub const LEN: usize = $len;
+
+            pub const fn new(bytes: [u8; $len]) -> Self {
+                Self(bytes)
+            }
+
+            pub const fn to_bytes(self) -> [u8; $len] {
+                self.0
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self([0; $len])
+            }
+        }
+
+        impl AsRef<[u8]> for $name {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl From<[u8; $len]> for $name {
+            fn from(bytes: [u8; $len]) -> Self {
+                Self(bytes)
+            }
+        }
+
+        impl From<$name> for [u8; $len] {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl TryFrom<&[u8]> for $name {
+            type Error = ParseBase58Error;
+
+            fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+                bytes.try_into().map(Self).map_err(|_| ParseBase58Error::WrongSize {
+                    expected: $len,
+                    actual: bytes.len(),
+                })
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseBase58Error;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let bytes =
+                    bs58::decode(s).into_vec().map_err(|_| ParseBase58Error::Invalid)?;
+                Self::try_from(bytes.as_slice())
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.pad(&bs58::encode(&self.0).into_string())
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(self, f)
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                s.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                String::deserialize(d)?.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+base58_bytes! {
+    /// An ed25519 transaction signature.
+    ///
+    /// This is the same type with or without the `sdk` features, and converts to and from
+    /// `solana_sdk::signature::Signature` with `sdk-full`.
+    Signature(64)
+}
+
+#[cfg(feature = "sdk-full")]
+impl From<Signature> for solana_sdk::signature::Signature {
+    fn from(value: Signature) -> Self {
+        Self::from(value.0)
+    }
+}
+
+#[cfg(feature = "sdk-full")]
+impl From<solana_sdk::signature::Signature> for Signature {
+    fn from(value: solana_sdk::signature::Signature) -> Self {
+        Self(value.into())
+    }
+}
+
 #[cfg(any(feature = "sdk", feature = "sdk-full"))]
 mod imp {
     pub use solana_sdk::{account::Account, hash::Hash, pubkey::Pubkey};
-
-    #[cfg(feature = "sdk-full")]
-    pub use solana_sdk::signature::Signature;
-
-    #[cfg(not(feature = "sdk-full"))]
-    pub type Signature = String;
 }
 
 #[cfg(not(any(feature = "sdk", feature = "sdk-full")))]
-#[allow(dead_code)]
 mod imp {
-    pub type Account = String;
-    pub type Hash = String;
-    pub type Signature = String;
-    pub type Pubkey = String;
+    use super::*;
+
+    base58_bytes! {
+        /// An account address.
+        Pubkey(32)
+    }
+
+    base58_bytes! {
+        /// A SHA-256 hash, e.g. a blockhash.
+        Hash(32)
+    }
+
+    /// An account, as stored on chain.
+    #[derive(Clone, Debug, Default, PartialEq, Eq)]
+    pub struct Account {
+        pub lamports: u64,
+        pub data: Vec<u8>,
+        pub owner: Pubkey,
+        pub executable: bool,
+        pub rent_epoch: u64,
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_base58_bytes() {
+            let s = "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T";
+            let pubkey: Pubkey = s.parse().unwrap();
+            assert_eq!(pubkey.to_string(), s);
+            assert_eq!(format!("{pubkey:?}"), s);
+            assert_eq!(serde_json::to_string(&pubkey).unwrap(), format!("\"{s}\""));
+            assert_eq!(serde_json::from_str::<Pubkey>(&format!("\"{s}\"")).unwrap(), pubkey);
+            assert_eq!(Pubkey::default().to_string(), "11111111111111111111111111111111");
+
+            assert_eq!("0OIl".parse::<Hash>(), Err(ParseBase58Error::Invalid));
+        }
+    }
 }
 
 pub use self::imp::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature() {
+        let signature = Signature([7; 64]);
+        let s = signature.to_string();
+        assert_eq!(s.parse::<Signature>().unwrap(), signature);
+        assert_eq!(serde_json::to_string(&signature).unwrap(), format!("\"{s}\""));
+        assert_eq!(
+            "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T".parse::<Signature>(),
+            Err(ParseBase58Error::WrongSize { expected: 64, actual: 32 })
+        );
+
+        #[cfg(feature = "sdk-full")]
+        {
+            let sdk_signature = solana_sdk::signature::Signature::from(signature);
+            assert_eq!(sdk_signature.to_string(), s);
+            assert_eq!(Signature::from(sdk_signature), signature);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Timestamps and dates, as [`chrono`] types with the `chrono` feature.
+
+use std::{
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+#[cfg(feature = "chrono")]
+mod imp {
+    /// A Unix timestamp, e.g. a block time.
+    pub type Timestamp = chrono::DateTime<chrono::Utc>;
+
+    /// An RFC 3339 date, e.g. `2021-11-10T14:24:11.849Z`.
+    pub type Date = chrono::DateTime<chrono::Utc>;
+}
+
+#[cfg(not(feature = "chrono"))]
+mod imp {
+    /// A Unix timestamp in seconds, e.g. a block time.
+    pub type Timestamp = u64;
+
+    /// An RFC 3339 date, e.g. `2021-11-10T14:24:11.849Z`.
+    pub type Date = String;
+}
+
+pub use self::imp::*;
+
+/// A Unix timestamp in seconds, used as a time-window bound in request filters.
+///
+/// Can be created from seconds, a [`SystemTime`], or a `chrono::DateTime` with the `chrono`
+/// feature.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UnixTime(pub u64);
+
+impl From<u64> for UnixTime {
+    fn from(secs: u64) -> Self {
+        Self(secs)
+    }
+}
+
+impl From<UnixTime> for u64 {
+    fn from(time: UnixTime) -> Self {
+        time.0
+    }
+}
+
+/// Times before the Unix epoch are clamped to `0`.
+impl From<SystemTime> for UnixTime {
+    fn from(time: SystemTime) -> Self {
+        Self(time.duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()))
+    }
+}
+
+/// Times before the Unix epoch are clamped to `0`.
+#[cfg(feature = "chrono")]
+impl<Tz: chrono::TimeZone> From<chrono::DateTime<Tz>> for UnixTime {
+    fn from(time: chrono::DateTime<Tz>) -> Self {
+        Self(time.timestamp().try_into().unwrap_or(0))
+    }
+}
+
+impl fmt::Display for UnixTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_unix_time() {
+        assert_eq!(UnixTime::from(1_700_000_000).to_string(), "1700000000");
+        let time = UNIX_EPOCH + Duration::from_millis(1_700_000_000_500);
+        assert_eq!(UnixTime::from(time), UnixTime(1_700_000_000));
+        assert_eq!(UnixTime::from(UNIX_EPOCH - Duration::from_secs(1)), UnixTime(0));
+
+        #[cfg(feature = "chrono")]
+        {
+            let time = chrono::DateTime::parse_from_rfc3339("2023-11-14T22:13:20Z").unwrap();
+            assert_eq!(UnixTime::from(time), UnixTime(1_700_000_000));
+        }
+    }
+}
@@ -0,0 +1,315 @@
+//! Conversions into [`solana_transaction_status`] types, as returned by the JSON-RPC API with the
+//! `jsonParsed` encoding.
+
+use crate::{
+    solana::Pubkey, AddressTableLookup, InnerInstructions, Instruction, TokenBalance,
+    TransactionAccountKey, TransactionConversionError, TransactionError, TransactionInfo,
+    TransactionMeta, TransactionVersion,
+};
+use solana_account_decoder::parse_token::UiTokenAmount;
+use solana_transaction_status::{
+    option_serializer::OptionSerializer,
+    parse_accounts::{ParsedAccount, ParsedAccountSource},
+    parse_instruction::ParsedInstruction,
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction,
+    EncodedTransactionWithStatusMeta, Rewards, UiAddressTableLookup, UiCompiledInstruction,
+    UiInnerInstructions, UiInstruction, UiLoadedAddresses, UiMessage, UiParsedInstruction,
+    UiParsedMessage, UiPartiallyDecodedInstruction, UiTransaction, UiTransactionStatusMeta,
+    UiTransactionTokenBalance,
+};
+
+type Result<T, E = TransactionConversionError> = std::result::Result<T, E>;
+
+impl TransactionInfo {
+    /// Converts the transaction into a JSON-RPC `getTransaction` response. `slot` and
+    /// `block_time` are not part of the transaction info, and must be provided by the caller.
+    pub fn try_into_encoded_confirmed(
+        self,
+        slot: u64,
+        block_time: Option<i64>,
+    ) -> Result<EncodedConfirmedTransactionWithStatusMeta> {
+        Ok(EncodedConfirmedTransactionWithStatusMeta {
+            slot,
+            transaction: self.try_into()?,
+            block_time,
+        })
+    }
+}
+
+impl TryFrom<TransactionInfo> for EncodedTransactionWithStatusMeta {
+    type Error = TransactionConversionError;
+
+    fn try_from(value: TransactionInfo) -> Result<Self> {
+        let TransactionInfo { meta, transaction, version } = value;
+        let message = transaction.message;
+
+        let is_versioned = matches!(version, Some(TransactionVersion::Number(_)));
+        let meta = match meta {
+            Some(meta) => {
+                let mut meta = UiTransactionStatusMeta::try_from(meta)?;
+                if is_versioned {
+                    meta.loaded_addresses =
+                        OptionSerializer::Some(loaded_addresses(&message.account_keys));
+                }
+                Some(meta)
+            }
+            None => None,
+        };
+
+        let message = UiParsedMessage {
+            account_keys: message.account_keys.into_iter().map(parsed_account).collect(),
+            recent_blockhash: message.recent_blockhash.to_string(),
+            instructions: message.instructions.into_iter().map(Into::into).collect(),
+            address_table_lookups: message
+                .address_table_lookups
+                .map(|lookups| lookups.into_iter().map(Into::into).collect()),
+        };
+        let transaction = UiTransaction {
+            signatures: transaction.signatures.iter().map(ToString::to_string).collect(),
+            message: UiMessage::Parsed(message),
+        };
+        Ok(Self {
+            transaction: EncodedTransaction::Json(transaction),
+            meta,
+            version: version.map(Into::into),
+        })
+    }
+}
+
+impl TryFrom<TransactionMeta> for UiTransactionStatusMeta {
+    type Error = TransactionConversionError;
+
+    fn try_from(value: TransactionMeta) -> Result<Self> {
+        let TransactionMeta {
+            err,
+            fee,
+            inner_instructions,
+            log_messages,
+            post_balances,
+            post_token_balances,
+            pre_balances,
+            pre_token_balances,
+            rewards,
+            status,
+        } = value;
+
+        let err = err.map(transaction_error).transpose()?;
+        let status = match status {
+            Some(Ok(())) => Ok(()),
+            Some(Err(err)) => Err(transaction_error(err)?),
+            None => err.clone().map_or(Ok(()), Err),
+        };
+        let rewards = rewards
+            .map(serde_json::from_value::<Rewards>)
+            .transpose()
+            .map_err(|e| TransactionConversionError::InvalidMeta(e.to_string()))?;
+        let token_balances = |balances: Vec<TokenBalance>| {
+            balances.into_iter().map(TryInto::try_into).collect::<Result<Vec<_>>>()
+        };
+
+        Ok(Self {
+            err,
+            status,
+            fee: fee.unwrap_or_default(),
+            pre_balances,
+            post_balances,
+            inner_instructions: OptionSerializer::Some(
+                inner_instructions.into_iter().map(Into::into).collect(),
+            ),
+            log_messages: OptionSerializer::Some(log_messages),
+            pre_token_balances: OptionSerializer::Some(token_balances(pre_token_balances)?),
+            post_token_balances: OptionSerializer::Some(token_balances(post_token_balances)?),
+            rewards: rewards.into(),
+            loaded_addresses: OptionSerializer::Skip,
+            return_data: OptionSerializer::Skip,
+            compute_units_consumed: OptionSerializer::Skip,
+        })
+    }
+}
+
+impl TryFrom<TokenBalance> for UiTransactionTokenBalance {
+    type Error = TransactionConversionError;
+
+    fn try_from(value: TokenBalance) -> Result<Self> {
+        let TokenBalance { account_index, mint, owner, program_id, ui_token_amount } = value;
+        let decimals = u8::try_from(ui_token_amount.decimals).map_err(|_| {
+            TransactionConversionError::InvalidMeta(format!(
+                "token decimals out of range: {}",
+                ui_token_amount.decimals
+            ))
+        })?;
+        let to_string = |pubkey: Option<Pubkey>| {
+            pubkey
+                .map_or(OptionSerializer::Skip, |pubkey| OptionSerializer::Some(pubkey.to_string()))
+        };
+        Ok(Self {
+            account_index,
+            mint: mint.to_string(),
+            ui_token_amount: UiTokenAmount {
+                ui_amount: ui_token_amount.ui_amount,
+                decimals,
+                amount: ui_token_amount.amount,
+                ui_amount_string: ui_token_amount.ui_amount_string,
+            },
+            owner: to_string(owner),
+            program_id: to_string(program_id),
+        })
+    }
+}
+
+impl From<InnerInstructions> for UiInnerInstructions {
+    fn from(value: InnerInstructions) -> Self {
+        let InnerInstructions { index, instructions } = value;
+        Self { index, instructions: instructions.into_iter().map(Into::into).collect() }
+    }
+}
+
+impl From<Instruction> for UiInstruction {
+    fn from(value: Instruction) -> Self {
+        match value {
+            Instruction::Compiled(ix) => Self::Compiled(UiCompiledInstruction {
+                program_id_index: ix.program_id_index,
+                accounts: ix.accounts,
+                data: ix.data,
+                stack_height: ix.stack_height,
+            }),
+            Instruction::Parsed(ix) => {
+                Self::Parsed(UiParsedInstruction::Parsed(ParsedInstruction {
+                    program: ix.program,
+                    program_id: ix.program_id.to_string(),
+                    parsed: ix.parsed,
+                    stack_height: ix.stack_height,
+                }))
+            }
+            Instruction::PartiallyDecoded(ix) => {
+                Self::Parsed(UiParsedInstruction::PartiallyDecoded(UiPartiallyDecodedInstruction {
+                    program_id: ix.program_id.to_string(),
+                    accounts: ix.accounts.iter().map(ToString::to_string).collect(),
+                    data: ix.data,
+                    stack_height: ix.stack_height,
+                }))
+            }
+        }
+    }
+}
+
+impl From<AddressTableLookup> for UiAddressTableLookup {
+    fn from(value: AddressTableLookup) -> Self {
+        let AddressTableLookup { account_key, writable_indexes, readonly_indexes } = value;
+        Self { account_key: account_key.to_string(), writable_indexes, readonly_indexes }
+    }
+}
+
+fn transaction_error(err: TransactionError) -> Result<solana_sdk::transaction::TransactionError> {
+    err.try_into().map_err(|err| {
+        TransactionConversionError::InvalidMeta(format!("unknown transaction error: {err:?}"))
+    })
+}
+
+fn parsed_account(key: TransactionAccountKey) -> ParsedAccount {
+    let source = match key.source.as_str() {
+        "transaction" => Some(ParsedAccountSource::Transaction),
+        "lookupTable" => Some(ParsedAccountSource::LookupTable),
+        _ => None,
+    };
+    ParsedAccount {
+        pubkey: key.pubkey.to_string(),
+        writable: key.writable,
+        signer: key.signer,
+        source,
+    }
+}
+
+fn loaded_addresses(account_keys: &[TransactionAccountKey]) -> UiLoadedAddresses {
+    let mut loaded = UiLoadedAddresses::default();
+    for key in account_keys.iter().filter(|key| key.source == "lookupTable") {
+        let addresses = if key.writable { &mut loaded.writable } else { &mut loaded.readonly };
+        addresses.push(key.pubkey.to_string());
+    }
+    loaded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encoded_transaction() {
+        let json = serde_json::json!({
+            "meta": {
+                "err": null,
+                "fee": 5000,
+                "innerInstructions": [{
+                    "index": 0,
+                    "instructions": [
+                        { "programIdIndex": 3, "accounts": [0, 1], "data": "3Bxs4h24hBtQy9rw", "stackHeight": 2 },
+                        {
+                            "program": "spl-memo",
+                            "programId": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr",
+                            "parsed": "hello",
+                            "stackHeight": 2
+                        }
+                    ]
+                }],
+                "logMessages": ["Program 11111111111111111111111111111111 invoke [1]"],
+                "postBalances": [999995000, 1000, 1, 1],
+                "postTokenBalances": [{
+                    "accountIndex": 2,
+                    "mint": "So11111111111111111111111111111111111111112",
+                    "owner": "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T",
+                    "programId": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+                    "uiTokenAmount": { "amount": "1500000", "decimals": 6, "uiAmount": 1.5, "uiAmountString": "1.5" }
+                }],
+                "preBalances": [1000000000, 0, 1, 1],
+                "preTokenBalances": [],
+                "rewards": [{
+                    "pubkey": "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T",
+                    "lamports": -5000,
+                    "postBalance": 999995000,
+                    "rewardType": "Fee",
+                    "commission": null
+                }],
+                "loadedAddresses": { "writable": ["9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM"], "readonly": [] },
+                "status": { "Ok": null }
+            },
+            "transaction": {
+                "message": {
+                    "accountKeys": [
+                        { "pubkey": "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T", "signer": true, "source": "transaction", "writable": true },
+                        { "pubkey": "2xNweLHLqrbx4zo1waDvgWJHgsUpPj8Y8icbAFeR4a8i", "signer": false, "source": "transaction", "writable": true },
+                        { "pubkey": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr", "signer": false, "source": "transaction", "writable": false },
+                        { "pubkey": "11111111111111111111111111111111", "signer": false, "source": "transaction", "writable": false },
+                        { "pubkey": "9WzDXwBbmkg8ZTbNMqUxvQRAyrZzDsGYdLVL9zYtAWWM", "signer": false, "source": "lookupTable", "writable": true }
+                    ],
+                    "addressTableLookups": [{
+                        "accountKey": "2immgwYNHBbyVQKVGCEkgWpi53bLwWNRMB5G2nbgYV17",
+                        "writableIndexes": [0],
+                        "readonlyIndexes": []
+                    }],
+                    "instructions": [{
+                        "accounts": [
+                            "4Nd1mBQtrMJVYVfKf2PJy9NZUZdTAsp7D4xWLs4gDB4T",
+                            "2xNweLHLqrbx4zo1waDvgWJHgsUpPj8Y8icbAFeR4a8i"
+                        ],
+                        "data": "3Bxs4h24hBtQy9rw",
+                        "programId": "11111111111111111111111111111111",
+                        "stackHeight": null
+                    }],
+                    "recentBlockhash": "EkSnNWid2cvwEVnVx9aBqawnmiCNiDgp3gUdkDPTKN1N"
+                },
+                "signatures": [
+                    "5VERv8NMvzbJMEkV8xnrLkEaWRtSz9CosKDYjCJjBRnbJLgp8uirBgmQpjKhoR4tjF3ZpRzrFmBV6UjKdiSZkQUW"
+                ]
+            },
+            "version": 0
+        });
+
+        let info: TransactionInfo = serde_json::from_value(json.clone()).unwrap();
+        let expected: EncodedTransactionWithStatusMeta = serde_json::from_value(json).unwrap();
+        assert_eq!(EncodedTransactionWithStatusMeta::try_from(info.clone()), Ok(expected));
+
+        let confirmed = info.try_into_encoded_confirmed(42, Some(1_700_000_000)).unwrap();
+        assert_eq!(confirmed.slot, 42);
+        assert_eq!(confirmed.block_time, Some(1_700_000_000));
+    }
+}